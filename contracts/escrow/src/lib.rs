@@ -1,10 +1,18 @@
 #![no_std]
 
 use shared::{
-    constants::{MIN_VALIDATORS, RESUME_TIME_DELAY, UPGRADE_TIME_LOCK_SECS},
+    constants::{
+        MAX_MILESTONE_DEADLINE, MAX_MILESTONE_RESUBMISSIONS, MILESTONE_APPROVAL_THRESHOLD,
+        MILESTONE_SETUP_PERIOD, MILESTONE_VOTE_QUORUM, MILESTONE_VOTING_PERIOD, MIN_VALIDATORS,
+        RESUME_TIME_DELAY, SUB_PROOF_PERIOD, UPGRADE_TIME_LOCK_SECS,
+    },
     errors::Error,
     events::*,
-    types::{Amount, EscrowInfo, Hash, Milestone, MilestoneStatus, PauseState, PendingUpgrade},
+    types::{
+        Amount, EscrowInfo, FeeConfig, Hash, Milestone, MilestoneRelease, MilestoneStatus,
+        MilestoneVoting, PauseState, PendingUpgrade, Timestamp, TrancheRelease, TrancheSchedule,
+    },
+    utils::{calculate_fee, validate_fee_config},
     MAX_APPROVAL_THRESHOLD, MIN_APPROVAL_THRESHOLD,
 };
 use soroban_sdk::{contract, contractimpl, token::TokenClient, Address, BytesN, Env, Vec};
@@ -17,6 +25,13 @@ mod tests;
 
 use storage::*;
 
+// Interface for ProjectLaunch, used to weight backer votes
+#[soroban_sdk::contractclient(name = "ProjectLaunchClient")]
+pub trait ProjectLaunchTrait {
+    fn get_user_contribution(env: Env, project_id: u64, contributor: Address) -> i128;
+    fn get_total_contributed(env: Env, project_id: u64) -> Result<i128, Error>;
+}
+
 #[contract]
 pub struct EscrowContract;
 
@@ -32,7 +47,29 @@ impl EscrowContract {
         Ok(())
     }
 
-    /// Initialize an escrow for a project
+    /// Set the ProjectLaunch contract that opens escrows for funded projects. Admin only.
+    /// Once set, only that contract may initialize escrows, so project ids cannot be taken
+    /// before their projects finish funding.
+    pub fn set_project_launch(env: Env, project_launch: Address) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        set_project_launch(&env, &project_launch);
+        Ok(())
+    }
+
+    /// Get the ProjectLaunch contract that opens escrows, if one has been configured
+    pub fn get_project_launch(env: Env) -> Option<Address> {
+        get_project_launch(&env)
+    }
+
+    /// Initialize an escrow for a project. Authorized by the creator, or by the configured
+    /// ProjectLaunch contract once one is set, which then also receives any refund. ProjectLaunch
+    /// charges the platform fee when it deposits, so its escrows are not charged again.
+    /// With `MilestoneVoting::Backers`, `validators` and `approval_threshold` are unused and
+    /// milestones are decided by MILESTONE_APPROVAL_THRESHOLD of the contributed amount.
+    /// Milestones must cover the deposit within MILESTONE_SETUP_PERIOD of opening, or the
+    /// escrow can be refunded.
     pub fn initialize(
         env: Env,
         project_id: u64,
@@ -40,11 +77,16 @@ impl EscrowContract {
         token: Address,
         validators: Vec<Address>,
         approval_threshold: u32,
+        voting: MilestoneVoting,
     ) -> Result<(), Error> {
-        creator.require_auth();
+        let project_launch = get_project_launch(&env);
+        match &project_launch {
+            Some(project_launch) => project_launch.require_auth(),
+            None => creator.require_auth(),
+        }
 
         // FIX: Removed unnecessary cast
-        if validators.len() < MIN_VALIDATORS {
+        if voting == MilestoneVoting::Validators && validators.len() < MIN_VALIDATORS {
             return Err(Error::InvalidInput);
         }
 
@@ -67,7 +109,13 @@ impl EscrowContract {
         };
 
         set_escrow(&env, project_id, &escrow);
+        set_voting(&env, project_id, &voting);
+        set_opened_at(&env, project_id, env.ledger().timestamp());
         set_milestone_counter(&env, project_id, 0);
+        if let Some(project_launch) = project_launch {
+            set_refund_address(&env, project_id, &project_launch);
+            set_fee_paid(&env, project_id);
+        }
 
         env.events()
             .publish((ESCROW_INITIALIZED,), (project_id, creator, token));
@@ -75,7 +123,9 @@ impl EscrowContract {
         Ok(())
     }
 
-    pub fn deposit(env: Env, project_id: u64, amount: Amount) -> Result<(), Error> {
+    /// Lock `amount` of the escrow token from `from` for a project
+    pub fn deposit(env: Env, project_id: u64, from: Address, amount: Amount) -> Result<(), Error> {
+        from.require_auth();
         let mut escrow = get_escrow(&env, project_id)?;
 
         if amount <= 0 {
@@ -92,6 +142,11 @@ impl EscrowContract {
             .ok_or(Error::InvalidInput)?;
 
         set_escrow(&env, project_id, &escrow);
+        TokenClient::new(&env, &escrow.token).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
         env.events().publish((FUNDS_LOCKED,), (project_id, amount));
 
         Ok(())
@@ -102,14 +157,22 @@ impl EscrowContract {
         project_id: u64,
         description_hash: Hash,
         amount: Amount,
+        deadline: Timestamp,
     ) -> Result<(), Error> {
         let escrow = get_escrow(&env, project_id)?;
         escrow.creator.require_auth();
 
-        if amount <= 0 {
+        if amount <= 0 || deadline <= env.ledger().timestamp() {
             return Err(Error::InvalidInput);
         }
 
+        // Deadlines cannot postpone a default indefinitely
+        if get_opened_at(&env, project_id)
+            .is_some_and(|opened_at| deadline > opened_at + MAX_MILESTONE_DEADLINE)
+        {
+            return Err(Error::InvalidDeadline);
+        }
+
         let total_milestones = get_total_milestone_amount(&env, project_id)?;
         let new_total = total_milestones
             .checked_add(amount)
@@ -140,6 +203,7 @@ impl EscrowContract {
         };
 
         set_milestone(&env, project_id, milestone_id, &milestone);
+        set_milestone_deadline(&env, project_id, milestone_id, deadline);
         set_milestone_counter(&env, project_id, next_id);
 
         env.events().publish(
@@ -165,6 +229,10 @@ impl EscrowContract {
             return Err(Error::InvalidMilestoneStatus);
         }
 
+        if env.ledger().timestamp() > get_milestone_deadline(&env, project_id, milestone_id) {
+            return Err(Error::DeadlinePassed);
+        }
+
         if is_paused(&env) {
             return Err(Error::ContractPaused);
         }
@@ -173,8 +241,10 @@ impl EscrowContract {
         milestone.proof_hash = proof_hash.clone();
 
         set_milestone(&env, project_id, milestone_id, &milestone);
+        open_vote(&env, project_id, milestone_id);
         set_milestone_votes(&env, project_id, milestone_id, 0, 0);
         clear_milestone_voters(&env, project_id, milestone_id);
+        set_backer_tally(&env, project_id, milestone_id, 0, 0);
 
         env.events().publish(
             (MILESTONE_SUBMITTED,),
@@ -184,6 +254,57 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Resubmit a rejected milestone with new proof, resetting its votes for a fresh round.
+    /// A milestone may be resubmitted at most MAX_MILESTONE_RESUBMISSIONS times, before its
+    /// deadline or, for a rejected sub-proof, before the tranche's sub-proof deadline.
+    pub fn resubmit_milestone(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        proof_hash: Hash,
+    ) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        if milestone.status != MilestoneStatus::Rejected {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        if env.ledger().timestamp() > proof_deadline(&env, project_id, milestone_id) {
+            return Err(Error::DeadlinePassed);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let resubmissions = get_resubmission_count(&env, project_id, milestone_id);
+        if resubmissions >= MAX_MILESTONE_RESUBMISSIONS || proof_hash == milestone.proof_hash {
+            return Err(Error::InvalidInput);
+        }
+
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.proof_hash = proof_hash.clone();
+        milestone.approval_count = 0;
+        milestone.rejection_count = 0;
+
+        set_milestone(&env, project_id, milestone_id, &milestone);
+        open_vote(&env, project_id, milestone_id);
+        clear_milestone_voters(&env, project_id, milestone_id);
+        set_backer_tally(&env, project_id, milestone_id, 0, 0);
+        increment_vote_round(&env, project_id, milestone_id);
+        set_resubmission_count(&env, project_id, milestone_id, resubmissions + 1);
+
+        env.events().publish(
+            (MILESTONE_RESUBMITTED,),
+            (project_id, milestone_id, proof_hash, resubmissions + 1),
+        );
+
+        Ok(())
+    }
+
     pub fn vote_milestone(
         env: Env,
         project_id: u64,
@@ -194,6 +315,16 @@ impl EscrowContract {
         voter.require_auth();
 
         let mut escrow = get_escrow(&env, project_id)?;
+        if let MilestoneVoting::Backers(project_launch) = get_voting(&env, project_id) {
+            return vote_as_backer(
+                &env,
+                &mut escrow,
+                &project_launch,
+                milestone_id,
+                &voter,
+                approve,
+            );
+        }
         validation::validate_validator(&escrow, &voter)?;
 
         let mut milestone = get_milestone(&env, project_id, milestone_id)?;
@@ -202,6 +333,10 @@ impl EscrowContract {
             return Err(Error::InvalidMilestoneStatus);
         }
 
+        if env.ledger().timestamp() > get_voting_ends_at(&env, project_id, milestone_id) {
+            return Err(Error::DeadlinePassed);
+        }
+
         if has_validator_voted(&env, project_id, milestone_id, &voter)? {
             return Err(Error::AlreadyVoted);
         }
@@ -227,59 +362,373 @@ impl EscrowContract {
         let required_approvals = (escrow.validators.len() * escrow.approval_threshold) / 10000;
 
         if milestone.approval_count as u32 >= required_approvals {
-            milestone.status = MilestoneStatus::Approved;
+            approve_milestone(&env, &mut escrow, &mut milestone)?;
+        } else if milestone.rejection_count as u32 > escrow.validators.len() - required_approvals {
+            reject_milestone(&env, &mut milestone);
+        } else {
+            set_milestone(&env, project_id, milestone_id, &milestone);
+        }
 
-            release_milestone_funds(&env, &mut escrow, &milestone)?;
+        Ok(())
+    }
 
-            let token_client = TokenClient::new(&env, &escrow.token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &escrow.creator,
-                &milestone.amount,
-            );
+    /// Resolve a submitted milestone once its voting window has closed. Anyone may call this.
+    /// The milestone is approved if at least MILESTONE_VOTE_QUORUM of validators voted and
+    /// approvals outnumber rejections; otherwise it is rejected and may be resubmitted.
+    pub fn finalize_milestone_vote(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<MilestoneStatus, Error> {
+        let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
 
-            set_escrow(&env, project_id, &escrow);
-            set_milestone(&env, project_id, milestone_id, &milestone);
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(Error::InvalidMilestoneStatus);
+        }
 
-            env.events().publish(
-                (MILESTONE_APPROVED,),
-                (project_id, milestone_id, milestone.approval_count),
-            );
+        if env.ledger().timestamp() <= get_voting_ends_at(&env, project_id, milestone_id) {
+            return Err(Error::InvalidInput);
+        }
 
-            env.events().publish(
-                (FUNDS_RELEASED,),
-                (project_id, milestone_id, milestone.amount),
-            );
-        } else if milestone.rejection_count as u32 > escrow.validators.len() - required_approvals {
-            milestone.status = MilestoneStatus::Rejected;
-            set_milestone(&env, project_id, milestone_id, &milestone);
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
 
-            env.events().publish(
-                (MILESTONE_REJECTED,),
-                (project_id, milestone_id, milestone.rejection_count),
-            );
+        let (quorum_reached, approved) = match get_voting(&env, project_id) {
+            MilestoneVoting::Validators => {
+                let votes = milestone.approval_count + milestone.rejection_count;
+                (
+                    votes * 10000 >= escrow.validators.len() * MILESTONE_VOTE_QUORUM,
+                    milestone.approval_count > milestone.rejection_count,
+                )
+            }
+            MilestoneVoting::Backers(project_launch) => {
+                let total = ProjectLaunchClient::new(&env, &project_launch)
+                    .get_total_contributed(&project_id);
+                let (approvals, rejections) = get_backer_tally(&env, project_id, milestone_id);
+                (
+                    (approvals + rejections) * 10000 >= total * MILESTONE_VOTE_QUORUM as i128,
+                    approvals > rejections,
+                )
+            }
+        };
+        if quorum_reached && approved {
+            approve_milestone(&env, &mut escrow, &mut milestone)?;
         } else {
-            set_milestone(&env, project_id, milestone_id, &milestone);
+            reject_milestone(&env, &mut milestone);
         }
 
+        Ok(milestone.status)
+    }
+
+    /// Pay a pending milestone out in tranches: `upfront_bps` on approval and the rest
+    /// either vested linearly or released as follow-up sub-proofs are approved. Creator only.
+    pub fn set_tranche_schedule(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        schedule: TrancheSchedule,
+    ) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        let milestone = get_milestone(&env, project_id, milestone_id)?;
+        if milestone.status != MilestoneStatus::Pending {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        let valid_remainder = match schedule.remainder {
+            TrancheRelease::Linear(duration) => duration > 0,
+            TrancheRelease::SubProofs(count) => count > 0,
+        };
+        if !valid_remainder || schedule.upfront_bps >= 10000 {
+            return Err(Error::InvalidInput);
+        }
+
+        set_tranche_schedule(&env, project_id, milestone_id, &schedule);
         Ok(())
     }
 
+    /// Submit a follow-up sub-proof for an approved milestone whose remainder is released
+    /// on sub-proofs. The milestone goes back to a vote; approval releases the next tranche.
+    pub fn submit_tranche_proof(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        proof_hash: Hash,
+    ) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+        if milestone.status != MilestoneStatus::Approved {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let count = match get_tranche_schedule(&env, project_id, milestone_id) {
+            Some(TrancheSchedule {
+                remainder: TrancheRelease::SubProofs(count),
+                ..
+            }) => count,
+            _ => return Err(Error::InvalidInput),
+        };
+        let release =
+            get_milestone_release(&env, project_id, milestone_id).ok_or(Error::NotFound)?;
+        if release.sub_proofs_approved >= count {
+            return Err(Error::NoClaimableAmount);
+        }
+        if env.ledger().timestamp() > proof_deadline(&env, project_id, milestone_id) {
+            return Err(Error::DeadlinePassed);
+        }
+
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.proof_hash = proof_hash.clone();
+        milestone.approval_count = 0;
+        milestone.rejection_count = 0;
+
+        set_milestone(&env, project_id, milestone_id, &milestone);
+        open_vote(&env, project_id, milestone_id);
+        clear_milestone_voters(&env, project_id, milestone_id);
+        set_backer_tally(&env, project_id, milestone_id, 0, 0);
+        increment_vote_round(&env, project_id, milestone_id);
+
+        env.events().publish(
+            (MILESTONE_SUBMITTED,),
+            (project_id, milestone_id, proof_hash),
+        );
+
+        Ok(())
+    }
+
+    /// Release the vested part of an approved milestone with a linear tranche schedule.
+    /// Returns the amount released before fees. Creator only.
+    pub fn claim_vested(env: Env, project_id: u64, milestone_id: u64) -> Result<Amount, Error> {
+        let mut escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let milestone = get_milestone(&env, project_id, milestone_id)?;
+        if milestone.status != MilestoneStatus::Approved {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        let schedule =
+            get_tranche_schedule(&env, project_id, milestone_id).ok_or(Error::InvalidInput)?;
+        let duration = match schedule.remainder {
+            TrancheRelease::Linear(duration) => duration,
+            TrancheRelease::SubProofs(_) => return Err(Error::InvalidInput),
+        };
+        let mut release =
+            get_milestone_release(&env, project_id, milestone_id).ok_or(Error::NotFound)?;
+
+        let upfront = upfront_amount(&milestone, &schedule);
+        let elapsed = (env.ledger().timestamp() - release.approved_at).min(duration);
+        let vested = upfront + (milestone.amount - upfront) * elapsed as i128 / duration as i128;
+        let claimable = vested - release.released;
+        if claimable <= 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        pay_creator(&env, &mut escrow, milestone_id, claimable)?;
+        release.released += claimable;
+        set_milestone_release(&env, project_id, milestone_id, &release);
+
+        Ok(claimable)
+    }
+
+    /// Get a milestone's tranche schedule, if it has one
+    pub fn get_tranche_schedule(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Option<TrancheSchedule> {
+        get_tranche_schedule(&env, project_id, milestone_id)
+    }
+
+    /// Get how much of a milestone has been paid out, once approved
+    pub fn get_milestone_release(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Option<MilestoneRelease> {
+        get_milestone_release(&env, project_id, milestone_id)
+    }
+
+    /// Set where unreleased funds are returned if the escrow is refunded, for escrows not
+    /// opened by the ProjectLaunch contract. Admin only; can be set once.
+    pub fn set_refund_address(
+        env: Env,
+        project_id: u64,
+        refund_address: Address,
+    ) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+        get_escrow(&env, project_id)?;
+
+        if get_refund_address(&env, project_id).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        set_refund_address(&env, project_id, &refund_address);
+        Ok(())
+    }
+
+    /// Return the unreleased balance to the refund address once a milestone has missed its
+    /// deadline without proof, or was rejected and can no longer be resubmitted, or once
+    /// milestones still do not cover the deposit after MILESTONE_SETUP_PERIOD. Approved
+    /// milestones released on sub-proofs default once a tranche misses its sub-proof deadline.
+    /// Anyone may call this. No further milestones can be released afterwards.
+    pub fn trigger_refund(env: Env, project_id: u64) -> Result<Amount, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        let refund_address = get_refund_address(&env, project_id).ok_or(Error::NotFound)?;
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut defaulted = now > get_setup_deadline(&env, project_id)
+            && get_total_milestone_amount(&env, project_id)? < escrow.total_deposited;
+        for milestone_id in 0..get_milestone_counter(&env, project_id)? {
+            if defaulted {
+                break;
+            }
+            let milestone = get_milestone(&env, project_id, milestone_id)?;
+            let deadline = proof_deadline(&env, project_id, milestone_id);
+            defaulted = match milestone.status {
+                MilestoneStatus::Pending | MilestoneStatus::Approved => now > deadline,
+                MilestoneStatus::Rejected => {
+                    now > deadline
+                        || get_resubmission_count(&env, project_id, milestone_id)
+                            >= MAX_MILESTONE_RESUBMISSIONS
+                }
+                _ => false,
+            };
+        }
+        if !defaulted {
+            return Err(Error::InvalidMilestoneStatus);
+        }
+
+        // Only what this project deposited and has not yet released can be refunded
+        let remaining = available_balance(&env, &escrow);
+        if remaining <= 0 {
+            return Err(Error::NoClaimableAmount);
+        }
+
+        set_refunded(&env, project_id, get_refunded(&env, project_id) + remaining);
+
+        TokenClient::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &refund_address,
+            &remaining,
+        );
+
+        env.events()
+            .publish((ESCROW_REFUNDED,), (project_id, refund_address, remaining));
+
+        Ok(remaining)
+    }
+
+    /// Get where unreleased funds are returned if the escrow is refunded
+    pub fn get_refund_address(env: Env, project_id: u64) -> Option<Address> {
+        get_refund_address(&env, project_id)
+    }
+
+    /// Get the amount returned to the refund address, 0 if the escrow was never refunded
+    pub fn get_refunded_amount(env: Env, project_id: u64) -> Amount {
+        get_refunded(&env, project_id)
+    }
+
     pub fn get_escrow(env: Env, project_id: u64) -> Result<EscrowInfo, Error> {
         get_escrow(&env, project_id)
     }
 
+    /// Get who votes on an escrow's milestones
+    pub fn get_voting(env: Env, project_id: u64) -> MilestoneVoting {
+        get_voting(&env, project_id)
+    }
+
     pub fn get_milestone(env: Env, project_id: u64, milestone_id: u64) -> Result<Milestone, Error> {
         get_milestone(&env, project_id, milestone_id)
     }
 
+    /// Get the time by which a milestone's proof must be submitted
+    pub fn get_milestone_deadline(env: Env, project_id: u64, milestone_id: u64) -> Timestamp {
+        get_milestone_deadline(&env, project_id, milestone_id)
+    }
+
+    /// Get the time by which the next sub-proof of an approved milestone must be approved,
+    /// if it still has sub-proof tranches to release
+    pub fn get_sub_proof_deadline(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Option<Timestamp> {
+        sub_proof_deadline(&env, project_id, milestone_id)
+    }
+
+    /// Get the time by which milestones must cover an escrow's deposit
+    pub fn get_setup_deadline(env: Env, project_id: u64) -> Timestamp {
+        get_setup_deadline(&env, project_id)
+    }
+
+    /// Get when the current vote on a milestone closes
+    pub fn get_voting_ends_at(env: Env, project_id: u64, milestone_id: u64) -> Timestamp {
+        get_voting_ends_at(&env, project_id, milestone_id)
+    }
+
+    /// Get how many times a rejected milestone has been resubmitted
+    pub fn get_resubmission_count(env: Env, project_id: u64, milestone_id: u64) -> u32 {
+        get_resubmission_count(&env, project_id, milestone_id)
+    }
+
+    /// Get the contribution-weighted approvals and rejections of a milestone's current vote
+    pub fn get_backer_tally(env: Env, project_id: u64, milestone_id: u64) -> (Amount, Amount) {
+        get_backer_tally(&env, project_id, milestone_id)
+    }
+
     pub fn get_total_milestone_amount(env: Env, project_id: u64) -> Result<Amount, Error> {
         get_total_milestone_amount(&env, project_id)
     }
 
     pub fn get_available_balance(env: Env, project_id: u64) -> Result<Amount, Error> {
         let escrow = get_escrow(&env, project_id)?;
-        Ok(escrow.total_deposited - escrow.released_amount)
+        Ok(available_balance(&env, &escrow))
+    }
+
+    /// Set the platform fee deducted from milestone releases of escrows not opened by the
+    /// ProjectLaunch contract (admin only)
+    pub fn set_fee_config(env: Env, config: FeeConfig) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        if !validate_fee_config(&config) {
+            return Err(Error::InvalidInput);
+        }
+
+        set_fee_config(&env, &config);
+        env.events().publish(
+            (FEE_CONFIG_UPDATED,),
+            (config.platform_fee, config.fee_recipient),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        get_fee_config(&env)
+    }
+
+    pub fn get_fees_collected(env: Env, token: Address) -> Amount {
+        get_fees_collected(&env, &token)
     }
 
     pub fn update_validators(
@@ -419,20 +868,250 @@ impl EscrowContract {
     }
 }
 
+/// Cast a backer's vote, weighted by their contribution to the project. The milestone is
+/// approved or rejected as soon as either side can no longer be outvoted.
+fn vote_as_backer(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    project_launch: &Address,
+    milestone_id: u64,
+    voter: &Address,
+    approve: bool,
+) -> Result<(), Error> {
+    let project_id = escrow.project_id;
+    let mut milestone = get_milestone(env, project_id, milestone_id)?;
+
+    if milestone.status != MilestoneStatus::Submitted {
+        return Err(Error::InvalidMilestoneStatus);
+    }
+
+    if env.ledger().timestamp() > get_voting_ends_at(env, project_id, milestone_id) {
+        return Err(Error::DeadlinePassed);
+    }
+
+    // Votes are tracked per voting round, as backers cannot be enumerated
+    let round = get_vote_round(env, project_id, milestone_id);
+    if has_backer_voted(env, project_id, milestone_id, round, voter) {
+        return Err(Error::AlreadyVoted);
+    }
+
+    let launch_client = ProjectLaunchClient::new(env, project_launch);
+    let weight = launch_client.get_user_contribution(&project_id, voter);
+    if weight <= 0 {
+        return Err(Error::InsufficientVotingPower);
+    }
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    set_backer_vote(env, project_id, milestone_id, round, voter);
+
+    let (mut approvals, mut rejections) = get_backer_tally(env, project_id, milestone_id);
+    if approve {
+        approvals += weight;
+        milestone.approval_count += 1;
+    } else {
+        rejections += weight;
+        milestone.rejection_count += 1;
+    }
+    set_backer_tally(env, project_id, milestone_id, approvals, rejections);
+
+    let total = launch_client.get_total_contributed(&project_id);
+    let threshold = MILESTONE_APPROVAL_THRESHOLD as i128;
+    if approvals * 10000 >= total * threshold {
+        approve_milestone(env, escrow, &mut milestone)?;
+    } else if rejections * 10000 > total * (10000 - threshold) {
+        reject_milestone(env, &mut milestone);
+    } else {
+        set_milestone(env, project_id, milestone_id, &milestone);
+    }
+
+    Ok(())
+}
+
+/// Approve a milestone and pay the creator what the approval unlocks: the whole amount,
+/// or under a tranche schedule the upfront share or the next sub-proof tranche
+fn approve_milestone(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    milestone: &mut Milestone,
+) -> Result<(), Error> {
+    let project_id = milestone.project_id;
+    let milestone_id = milestone.id;
+    milestone.status = MilestoneStatus::Approved;
+
+    let schedule = get_tranche_schedule(env, project_id, milestone_id);
+    let (amount, release) = match (
+        &schedule,
+        get_milestone_release(env, project_id, milestone_id),
+    ) {
+        (None, _) => (
+            milestone.amount,
+            MilestoneRelease {
+                released: milestone.amount,
+                approved_at: env.ledger().timestamp(),
+                sub_proofs_approved: 0,
+            },
+        ),
+        (Some(schedule), None) => {
+            let upfront = upfront_amount(milestone, schedule);
+            (
+                upfront,
+                MilestoneRelease {
+                    released: upfront,
+                    approved_at: env.ledger().timestamp(),
+                    sub_proofs_approved: 0,
+                },
+            )
+        }
+        (Some(schedule), Some(mut release)) => {
+            // A follow-up sub-proof was approved; the last tranche takes any rounding remainder
+            let count = match schedule.remainder {
+                TrancheRelease::SubProofs(count) => count,
+                TrancheRelease::Linear(_) => return Err(Error::InvalidMilestoneStatus),
+            };
+            let remainder = milestone.amount - upfront_amount(milestone, schedule);
+            release.sub_proofs_approved += 1;
+            let amount = if release.sub_proofs_approved >= count {
+                milestone.amount - release.released
+            } else {
+                remainder / count as i128
+            };
+            release.released += amount;
+            (amount, release)
+        }
+    };
+
+    pay_creator(env, escrow, milestone_id, amount)?;
+    set_milestone_release(env, project_id, milestone_id, &release);
+    set_milestone(env, project_id, milestone_id, milestone);
+
+    env.events().publish(
+        (MILESTONE_APPROVED,),
+        (project_id, milestone_id, milestone.approval_count),
+    );
+
+    Ok(())
+}
+
+/// Share of a milestone released on approval under its tranche schedule
+fn upfront_amount(milestone: &Milestone, schedule: &TrancheSchedule) -> Amount {
+    milestone.amount * schedule.upfront_bps as i128 / 10000
+}
+
+/// Release `amount` of a milestone to the creator, less any platform fee not already charged
+fn pay_creator(
+    env: &Env,
+    escrow: &mut EscrowInfo,
+    milestone_id: u64,
+    amount: Amount,
+) -> Result<(), Error> {
+    let project_id = escrow.project_id;
+    release_milestone_funds(env, escrow, amount)?;
+
+    let token_client = TokenClient::new(env, &escrow.token);
+    let mut payout = amount;
+    let fee_config = if is_fee_paid(env, project_id) {
+        None
+    } else {
+        get_fee_config(env)
+    };
+    if let Some(config) = fee_config {
+        let fee = calculate_fee(amount, config.platform_fee);
+        if fee > 0 {
+            token_client.transfer(&env.current_contract_address(), &config.fee_recipient, &fee);
+            add_fees_collected(env, &escrow.token, fee);
+            payout -= fee;
+
+            env.events()
+                .publish((FEE_COLLECTED,), (project_id, milestone_id, fee));
+        }
+    }
+    if payout > 0 {
+        token_client.transfer(&env.current_contract_address(), &escrow.creator, &payout);
+    }
+
+    set_escrow(env, project_id, escrow);
+
+    env.events()
+        .publish((FUNDS_RELEASED,), (project_id, milestone_id, amount));
+
+    Ok(())
+}
+
+/// Reject a milestone; the creator may resubmit it while resubmissions remain
+fn reject_milestone(env: &Env, milestone: &mut Milestone) {
+    milestone.status = MilestoneStatus::Rejected;
+    set_milestone(env, milestone.project_id, milestone.id, milestone);
+
+    env.events().publish(
+        (MILESTONE_REJECTED,),
+        (
+            milestone.project_id,
+            milestone.id,
+            milestone.rejection_count,
+        ),
+    );
+}
+
+/// Funds deposited for a project that were neither released nor refunded
+fn available_balance(env: &Env, escrow: &EscrowInfo) -> Amount {
+    escrow.total_deposited - escrow.released_amount - get_refunded(env, escrow.project_id)
+}
+
+/// Time by which the next sub-proof tranche of an approved milestone must be approved
+fn sub_proof_deadline(env: &Env, project_id: u64, milestone_id: u64) -> Option<Timestamp> {
+    let count = match get_tranche_schedule(env, project_id, milestone_id) {
+        Some(TrancheSchedule {
+            remainder: TrancheRelease::SubProofs(count),
+            ..
+        }) => count,
+        _ => return None,
+    };
+    let release = get_milestone_release(env, project_id, milestone_id)?;
+    if release.sub_proofs_approved >= count {
+        return None;
+    }
+    Some(release.approved_at + (release.sub_proofs_approved as u64 + 1) * SUB_PROOF_PERIOD)
+}
+
+/// Time by which a milestone's outstanding proof must be submitted: its deadline until it
+/// is approved, then the next sub-proof deadline, if any
+fn proof_deadline(env: &Env, project_id: u64, milestone_id: u64) -> Timestamp {
+    match get_milestone_release(env, project_id, milestone_id) {
+        None => get_milestone_deadline(env, project_id, milestone_id),
+        Some(_) => sub_proof_deadline(env, project_id, milestone_id).unwrap_or(Timestamp::MAX),
+    }
+}
+
+/// Time by which milestones must cover an escrow's deposit; never for older escrows
+fn get_setup_deadline(env: &Env, project_id: u64) -> Timestamp {
+    get_opened_at(env, project_id)
+        .map(|opened_at| opened_at + MILESTONE_SETUP_PERIOD)
+        .unwrap_or(Timestamp::MAX)
+}
+
+/// Open a fresh MILESTONE_VOTING_PERIOD vote on a submitted milestone
+fn open_vote(env: &Env, project_id: u64, milestone_id: u64) {
+    let ends_at = env.ledger().timestamp() + MILESTONE_VOTING_PERIOD;
+    set_voting_ends_at(env, project_id, milestone_id, ends_at);
+}
+
 fn release_milestone_funds(
-    _env: &Env,
+    env: &Env,
     escrow: &mut EscrowInfo,
-    milestone: &Milestone,
+    amount: Amount,
 ) -> Result<(), Error> {
+    if amount > available_balance(env, escrow) {
+        return Err(Error::InsufficientEscrowBalance);
+    }
+
     let new_released = escrow
         .released_amount
-        .checked_add(milestone.amount)
+        .checked_add(amount)
         .ok_or(Error::InvalidInput)?;
 
-    if new_released > escrow.total_deposited {
-        return Err(Error::InsufficientEscrowBalance);
-    }
-
     escrow.released_amount = new_released;
     Ok(())
 }
@@ -1,5 +1,8 @@
 use shared::errors::Error;
-use shared::types::{Amount, EscrowInfo, Milestone, PauseState, PendingUpgrade};
+use shared::types::{
+    Amount, EscrowInfo, FeeConfig, Milestone, MilestoneRelease, MilestoneVoting, PauseState,
+    PendingUpgrade, Timestamp, TrancheSchedule,
+};
 use soroban_sdk::{Address, Env};
 
 /// Storage keys for escrow data structures
@@ -7,7 +10,23 @@ const ESCROW_PREFIX: &str = "escrow";
 const MILESTONE_PREFIX: &str = "milestone";
 const MILESTONE_COUNTER_PREFIX: &str = "m_counter";
 const VALIDATOR_VOTE_PREFIX: &str = "v_vote";
+const RESUBMISSION_PREFIX: &str = "m_resub";
+const REFUND_ADDRESS_PREFIX: &str = "refund_to";
+const REFUNDED_PREFIX: &str = "refunded";
+const FEE_PAID_PREFIX: &str = "fee_paid";
+const VOTING_PREFIX: &str = "voting";
+const MILESTONE_DEADLINE_PREFIX: &str = "m_due";
+const OPENED_AT_PREFIX: &str = "opened_at";
+const VOTING_ENDS_PREFIX: &str = "m_vote_end";
+const BACKER_VOTE_PREFIX: &str = "b_vote";
+const BACKER_TALLY_PREFIX: &str = "b_tally";
+const VOTE_ROUND_PREFIX: &str = "v_round";
+const TRANCHE_SCHEDULE_PREFIX: &str = "tranche";
+const MILESTONE_RELEASE_PREFIX: &str = "m_release";
 const ADMIN_KEY: &str = "admin";
+const PROJECT_LAUNCH_KEY: &str = "launch";
+const FEE_CONFIG_KEY: &str = "fee_config";
+const FEES_COLLECTED_PREFIX: &str = "fees";
 
 /// Store platform admin
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -27,12 +46,145 @@ pub fn has_admin(env: &Env) -> bool {
     env.storage().instance().has(&ADMIN_KEY)
 }
 
+/// Store the ProjectLaunch contract that opens escrows for funded projects
+pub fn set_project_launch(env: &Env, project_launch: &Address) {
+    env.storage()
+        .instance()
+        .set(&PROJECT_LAUNCH_KEY, project_launch);
+}
+
+/// Retrieve the ProjectLaunch contract, if one has been configured
+pub fn get_project_launch(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&PROJECT_LAUNCH_KEY)
+}
+
+/// Store the platform fee configuration
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&FEE_CONFIG_KEY, config);
+}
+
+/// Retrieve the platform fee configuration, if set
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&FEE_CONFIG_KEY)
+}
+
+/// Add to the platform fees collected in a token
+pub fn add_fees_collected(env: &Env, token: &Address, amount: Amount) {
+    let key = (FEES_COLLECTED_PREFIX, token.clone());
+    let collected = get_fees_collected(env, token);
+    env.storage().persistent().set(&key, &(collected + amount));
+}
+
+/// Retrieve the platform fees collected in a token
+pub fn get_fees_collected(env: &Env, token: &Address) -> Amount {
+    let key = (FEES_COLLECTED_PREFIX, token.clone());
+    env.storage()
+        .persistent()
+        .get::<(&str, Address), Amount>(&key)
+        .unwrap_or(0)
+}
+
 /// Store escrow information
 pub fn set_escrow(env: &Env, project_id: u64, escrow: &EscrowInfo) {
     let key = (ESCROW_PREFIX, project_id);
     env.storage().persistent().set(&key, escrow);
 }
 
+/// Store where unreleased funds are returned if the escrow is refunded
+pub fn set_refund_address(env: &Env, project_id: u64, refund_address: &Address) {
+    let key = (REFUND_ADDRESS_PREFIX, project_id);
+    env.storage().persistent().set(&key, refund_address);
+}
+
+/// Get where unreleased funds are returned if the escrow is refunded
+pub fn get_refund_address(env: &Env, project_id: u64) -> Option<Address> {
+    let key = (REFUND_ADDRESS_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store the amount returned to the refund address
+pub fn set_refunded(env: &Env, project_id: u64, amount: Amount) {
+    let key = (REFUNDED_PREFIX, project_id);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Get the amount returned to the refund address, 0 if the escrow was never refunded
+pub fn get_refunded(env: &Env, project_id: u64) -> Amount {
+    let key = (REFUNDED_PREFIX, project_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Record that the platform fee on an escrow's funds was charged before they were deposited
+pub fn set_fee_paid(env: &Env, project_id: u64) {
+    let key = (FEE_PAID_PREFIX, project_id);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check if the platform fee on an escrow's funds was charged before they were deposited
+pub fn is_fee_paid(env: &Env, project_id: u64) -> bool {
+    let key = (FEE_PAID_PREFIX, project_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Store who votes on an escrow's milestones
+pub fn set_voting(env: &Env, project_id: u64, voting: &MilestoneVoting) {
+    let key = (VOTING_PREFIX, project_id);
+    env.storage().persistent().set(&key, voting);
+}
+
+/// Get who votes on an escrow's milestones; escrows created before backer voting use validators
+pub fn get_voting(env: &Env, project_id: u64) -> MilestoneVoting {
+    let key = (VOTING_PREFIX, project_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(MilestoneVoting::Validators)
+}
+
+/// Store the time by which a milestone's proof must be submitted
+pub fn set_milestone_deadline(env: &Env, project_id: u64, milestone_id: u64, deadline: Timestamp) {
+    let key = (MILESTONE_DEADLINE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, &deadline);
+}
+
+/// Get a milestone's proof deadline; milestones created before deadlines never expire
+pub fn get_milestone_deadline(env: &Env, project_id: u64, milestone_id: u64) -> Timestamp {
+    let key = (MILESTONE_DEADLINE_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Timestamp::MAX)
+}
+
+/// Store when an escrow was opened
+pub fn set_opened_at(env: &Env, project_id: u64, opened_at: Timestamp) {
+    let key = (OPENED_AT_PREFIX, project_id);
+    env.storage().persistent().set(&key, &opened_at);
+}
+
+/// Get when an escrow was opened; escrows opened before this was recorded have no
+/// escrow-level deadlines
+pub fn get_opened_at(env: &Env, project_id: u64) -> Option<Timestamp> {
+    let key = (OPENED_AT_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store when the current vote on a milestone closes
+pub fn set_voting_ends_at(env: &Env, project_id: u64, milestone_id: u64, ends_at: Timestamp) {
+    let key = (VOTING_ENDS_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, &ends_at);
+}
+
+/// Get when the current vote on a milestone closes; votes opened before voting windows
+/// stay open until decided
+pub fn get_voting_ends_at(env: &Env, project_id: u64, milestone_id: u64) -> Timestamp {
+    let key = (VOTING_ENDS_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Timestamp::MAX)
+}
+
 /// Retrieve escrow information
 pub fn get_escrow(env: &Env, project_id: u64) -> Result<EscrowInfo, Error> {
     let key = (ESCROW_PREFIX, project_id);
@@ -101,6 +253,117 @@ pub fn has_validator_voted(
     Ok(env.storage().persistent().has(&key))
 }
 
+/// Store how many times a rejected milestone has been resubmitted
+pub fn set_resubmission_count(env: &Env, project_id: u64, milestone_id: u64, count: u32) {
+    let key = (RESUBMISSION_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Get how many times a rejected milestone has been resubmitted
+pub fn get_resubmission_count(env: &Env, project_id: u64, milestone_id: u64) -> u32 {
+    let key = (RESUBMISSION_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Start a new voting round for a milestone
+pub fn increment_vote_round(env: &Env, project_id: u64, milestone_id: u64) {
+    let key = (VOTE_ROUND_PREFIX, project_id, milestone_id);
+    let round = get_vote_round(env, project_id, milestone_id);
+    env.storage().persistent().set(&key, &(round + 1));
+}
+
+/// Get a milestone's current voting round
+pub fn get_vote_round(env: &Env, project_id: u64, milestone_id: u64) -> u32 {
+    let key = (VOTE_ROUND_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store a milestone's tranche schedule
+pub fn set_tranche_schedule(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    schedule: &TrancheSchedule,
+) {
+    let key = (TRANCHE_SCHEDULE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, schedule);
+}
+
+/// Get a milestone's tranche schedule, if it has one
+pub fn get_tranche_schedule(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+) -> Option<TrancheSchedule> {
+    let key = (TRANCHE_SCHEDULE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store the payout progress of an approved milestone
+pub fn set_milestone_release(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    release: &MilestoneRelease,
+) {
+    let key = (MILESTONE_RELEASE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, release);
+}
+
+/// Get the payout progress of a milestone, once approved
+pub fn get_milestone_release(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+) -> Option<MilestoneRelease> {
+    let key = (MILESTONE_RELEASE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Record that a backer voted in a milestone's current voting round
+pub fn set_backer_vote(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    round: u32,
+    backer: &Address,
+) {
+    let key = (BACKER_VOTE_PREFIX, project_id, milestone_id, round, backer);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Check if a backer has already voted in a milestone's current voting round
+pub fn has_backer_voted(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    round: u32,
+    backer: &Address,
+) -> bool {
+    let key = (BACKER_VOTE_PREFIX, project_id, milestone_id, round, backer);
+    env.storage().persistent().has(&key)
+}
+
+/// Store the contribution-weighted approvals and rejections for a milestone
+pub fn set_backer_tally(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    approvals: Amount,
+    rejections: Amount,
+) {
+    let key = (BACKER_TALLY_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .set(&key, &(approvals, rejections));
+}
+
+/// Get the contribution-weighted approvals and rejections for a milestone
+pub fn get_backer_tally(env: &Env, project_id: u64, milestone_id: u64) -> (Amount, Amount) {
+    let key = (BACKER_TALLY_PREFIX, project_id, milestone_id);
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
 /// Clear all validator votes for a milestone (used when resubmitting)
 pub fn clear_milestone_voters(env: &Env, project_id: u64, milestone_id: u64) {
     // Get the escrow to know how many validators there are
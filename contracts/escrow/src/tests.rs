@@ -1,8 +1,16 @@
 // FIX: Module inception error removed by deleting "mod tests {" wrapper
 use crate::{EscrowContract, EscrowContractClient};
+use shared::{
+    constants::{
+        MAX_MILESTONE_DEADLINE, MAX_MILESTONE_RESUBMISSIONS, MAX_PLATFORM_FEE,
+        MILESTONE_SETUP_PERIOD, MILESTONE_VOTING_PERIOD, SUB_PROOF_PERIOD,
+    },
+    types::{FeeConfig, MilestoneStatus, MilestoneVoting, TrancheRelease, TrancheSchedule},
+};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, Env, Vec,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env, IntoVal, Val, Vec,
 };
 
 fn create_test_env() -> (Env, Address, Address, Address, Vec<Address>) {
@@ -40,7 +48,9 @@ fn setup_with_admin(
 ) {
     let admin = Address::generate(env);
     let creator = Address::generate(env);
-    let token = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
 
     let mut validators = Vec::new(env);
     validators.push_back(Address::generate(env));
@@ -51,20 +61,41 @@ fn setup_with_admin(
     let client = EscrowContractClient::new(env, &contract_id);
 
     client.initialize_admin(&admin);
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
 
     (admin, creator, token, validators, client)
 }
 
 const DEFAULT_THRESHOLD: u32 = 6700;
 
+/// Mint `amount` of `token` to a new funder and deposit it into escrow `project_id`
+fn fund(env: &Env, client: &EscrowContractClient, project_id: u64, token: &Address, amount: i128) {
+    let funder = Address::generate(env);
+    StellarAssetClient::new(env, token).mint(&funder, &amount);
+    client.deposit(&project_id, &funder, &amount);
+}
+
 #[test]
 fn test_initialize_escrow() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
 
     let escrow = client.get_escrow(&1);
     assert_eq!(escrow.project_id, 1);
@@ -76,3 +107,435 @@ fn test_initialize_escrow() {
 }
 
 // ... other tests follow the same pattern
+
+#[test]
+fn test_milestone_release_deducts_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, _, validators, client) = setup_with_admin(&env);
+
+    let token_admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token = TokenClient::new(&env, &sac.address());
+    client.initialize(
+        &2,
+        &creator,
+        &sac.address(),
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
+    fund(&env, &client, 2, &sac.address(), 10_000);
+
+    let fee_recipient = Address::generate(&env);
+    let config = FeeConfig {
+        platform_fee: MAX_PLATFORM_FEE + 1,
+        creator_fee: 0,
+        fee_recipient: fee_recipient.clone(),
+        flexible_fee_premium: 0,
+    };
+    assert!(client.try_set_fee_config(&config).is_err());
+    client.set_fee_config(&FeeConfig {
+        platform_fee: 250,
+        ..config
+    });
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.create_milestone(&2, &hash, &4_000, &100_000);
+    client.submit_milestone(&2, &0, &hash);
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+
+    assert_eq!(token.balance(&fee_recipient), 100);
+    assert_eq!(token.balance(&creator), 3_900);
+    assert_eq!(client.get_fees_collected(&sac.address()), 100);
+}
+
+#[test]
+fn test_resubmit_rejected_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, token, validators, client) = setup_with_admin(&env);
+
+    let description = BytesN::from_array(&env, &[1u8; 32]);
+    fund(&env, &client, 1, &token, 1_000);
+    client.create_milestone(&1, &description, &1_000, &100_000);
+
+    // Only rejected milestones can be resubmitted
+    let mut proof = BytesN::from_array(&env, &[2u8; 32]);
+    client.submit_milestone(&1, &0, &proof);
+    assert!(client.try_resubmit_milestone(&1, &0, &proof).is_err());
+
+    for attempt in 0..=MAX_MILESTONE_RESUBMISSIONS {
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false);
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+
+        proof = BytesN::from_array(&env, &[3 + attempt as u8; 32]);
+        if attempt == MAX_MILESTONE_RESUBMISSIONS {
+            assert!(client.try_resubmit_milestone(&1, &0, &proof).is_err());
+            break;
+        }
+        client.resubmit_milestone(&1, &0, &proof);
+
+        // Votes are reset so every validator can vote again
+        let milestone = client.get_milestone(&1, &0);
+        assert_eq!(milestone.status, MilestoneStatus::Submitted);
+        assert_eq!(milestone.proof_hash, proof);
+        assert_eq!(milestone.approval_count, 0);
+        assert_eq!(milestone.rejection_count, 0);
+        assert_eq!(client.get_resubmission_count(&1, &0), attempt + 1);
+    }
+}
+
+#[test]
+fn test_finalize_milestone_vote_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, _, validators, client) = setup_with_admin(&env);
+
+    // Every validator must approve for an early release
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    client.initialize(
+        &2,
+        &creator,
+        &sac.address(),
+        &validators,
+        &10000,
+        &MilestoneVoting::Validators,
+    );
+    fund(&env, &client, 2, &sac.address(), 10_000);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.create_milestone(&2, &hash, &4_000, &(MILESTONE_VOTING_PERIOD * 4));
+    client.submit_milestone(&2, &0, &hash);
+    let voting_ends_at = client.get_voting_ends_at(&2, &0);
+    assert_eq!(voting_ends_at, MILESTONE_VOTING_PERIOD);
+
+    // The vote cannot be finalized while the window is open
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    assert!(client.try_finalize_milestone_vote(&2, &0).is_err());
+
+    // A single vote out of three misses quorum, so the milestone is rejected
+    env.ledger().set_timestamp(voting_ends_at + 1);
+    let result = client.try_vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+    assert!(result.is_err());
+    assert_eq!(
+        client.finalize_milestone_vote(&2, &0),
+        MilestoneStatus::Rejected
+    );
+
+    // A majority of the votes cast approves and releases the milestone
+    let proof = BytesN::from_array(&env, &[2u8; 32]);
+    client.resubmit_milestone(&2, &0, &proof);
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(
+        client.get_milestone(&2, &0).status,
+        MilestoneStatus::Submitted
+    );
+    env.ledger()
+        .set_timestamp(client.get_voting_ends_at(&2, &0) + 1);
+    assert_eq!(
+        client.finalize_milestone_vote(&2, &0),
+        MilestoneStatus::Approved
+    );
+    assert_eq!(token.balance(&creator), 4_000);
+    assert!(client.try_finalize_milestone_vote(&2, &0).is_err());
+}
+
+#[test]
+fn test_tranche_schedules_release_in_parts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, _, validators, client) = setup_with_admin(&env);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    client.initialize(
+        &2,
+        &creator,
+        &sac.address(),
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
+    fund(&env, &client, 2, &sac.address(), 10_000);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let deadline = MILESTONE_VOTING_PERIOD * 4;
+    client.create_milestone(&2, &hash, &4_000, &deadline);
+    client.create_milestone(&2, &hash, &6_000, &deadline);
+
+    // 25% on approval, the rest vesting over 1000 seconds
+    let linear = TrancheSchedule {
+        upfront_bps: 2500,
+        remainder: TrancheRelease::Linear(1000),
+    };
+    let invalid = TrancheSchedule {
+        upfront_bps: 2500,
+        remainder: TrancheRelease::Linear(0),
+    };
+    assert!(client.try_set_tranche_schedule(&2, &0, &invalid).is_err());
+    client.set_tranche_schedule(&2, &0, &linear);
+
+    client.submit_milestone(&2, &0, &hash);
+    assert!(client.try_set_tranche_schedule(&2, &0, &linear).is_err());
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(token.balance(&creator), 1_000);
+    assert!(client.try_claim_vested(&2, &0).is_err());
+
+    let approved_at = client.get_milestone_release(&2, &0).unwrap().approved_at;
+    env.ledger().set_timestamp(approved_at + 400);
+    assert_eq!(client.claim_vested(&2, &0), 1_200);
+    env.ledger().set_timestamp(approved_at + 5_000);
+    assert_eq!(client.claim_vested(&2, &0), 1_800);
+    assert_eq!(token.balance(&creator), 4_000);
+    assert!(client.try_claim_vested(&2, &0).is_err());
+
+    // Half on approval, the rest over two approved sub-proofs
+    client.set_tranche_schedule(
+        &2,
+        &1,
+        &TrancheSchedule {
+            upfront_bps: 5000,
+            remainder: TrancheRelease::SubProofs(2),
+        },
+    );
+    client.submit_milestone(&2, &1, &hash);
+    client.vote_milestone(&2, &1, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &1, &validators.get(1).unwrap(), &true);
+    assert_eq!(token.balance(&creator), 7_000);
+    assert!(client.try_claim_vested(&2, &1).is_err());
+
+    for round in 0..2u8 {
+        let proof = BytesN::from_array(&env, &[2 + round; 32]);
+        client.submit_tranche_proof(&2, &1, &proof);
+        assert_eq!(
+            client.get_milestone(&2, &1).status,
+            MilestoneStatus::Submitted
+        );
+        // Validators who voted on the milestone can vote on each sub-proof
+        client.vote_milestone(&2, &1, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&2, &1, &validators.get(1).unwrap(), &true);
+    }
+    assert_eq!(token.balance(&creator), 10_000);
+    assert_eq!(
+        client.get_milestone_release(&2, &1).unwrap().released,
+        6_000
+    );
+    let proof = BytesN::from_array(&env, &[9u8; 32]);
+    assert!(client.try_submit_tranche_proof(&2, &1, &proof).is_err());
+}
+
+#[test]
+fn test_sub_proof_tranches_have_their_own_deadlines() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, token, validators, client) = setup_with_admin(&env);
+    let token_client = TokenClient::new(&env, &token);
+    client.initialize(
+        &2,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
+    fund(&env, &client, 2, &token, 6_000);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let deadline = MILESTONE_VOTING_PERIOD * 4;
+    client.create_milestone(&2, &hash, &6_000, &deadline);
+    client.set_tranche_schedule(
+        &2,
+        &0,
+        &TrancheSchedule {
+            upfront_bps: 5000,
+            remainder: TrancheRelease::SubProofs(2),
+        },
+    );
+    client.submit_milestone(&2, &0, &hash);
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(token_client.balance(&creator), 3_000);
+
+    // A sub-proof rejected after the milestone deadline does not default the escrow
+    env.ledger().set_timestamp(deadline + 1);
+    client.submit_tranche_proof(&2, &0, &BytesN::from_array(&env, &[2u8; 32]));
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &false);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &false);
+    assert_eq!(
+        client.get_milestone(&2, &0).status,
+        MilestoneStatus::Rejected
+    );
+    assert!(client.try_trigger_refund(&2).is_err());
+
+    client.resubmit_milestone(&2, &0, &BytesN::from_array(&env, &[3u8; 32]));
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&2, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(token_client.balance(&creator), 4_500);
+
+    // Each tranche has its own sub-proof deadline; missing it refunds the unreleased rest
+    let approved_at = client.get_milestone_release(&2, &0).unwrap().approved_at;
+    let due = approved_at + 2 * SUB_PROOF_PERIOD;
+    assert_eq!(client.get_sub_proof_deadline(&2, &0), Some(due));
+    assert!(client.try_trigger_refund(&2).is_err());
+    env.ledger().set_timestamp(due + 1);
+    let proof = BytesN::from_array(&env, &[4u8; 32]);
+    assert!(client.try_submit_tranche_proof(&2, &0, &proof).is_err());
+    let backers = Address::generate(&env);
+    client.set_refund_address(&2, &backers);
+    assert_eq!(client.trigger_refund(&2), 1_500);
+    assert_eq!(token_client.balance(&backers), 1_500);
+}
+
+#[test]
+fn test_project_launch_opens_escrows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, token, validators, client) = setup_with_admin(&env);
+
+    let project_launch = Address::generate(&env);
+    client.set_project_launch(&project_launch);
+    assert_eq!(client.get_project_launch(), Some(project_launch.clone()));
+
+    // The creator alone can no longer claim a project id
+    let args: Vec<Val> = (
+        2u64,
+        creator.clone(),
+        token.clone(),
+        validators.clone(),
+        DEFAULT_THRESHOLD,
+        MilestoneVoting::Validators,
+    )
+        .into_val(&env);
+    for signer in [&creator, &project_launch] {
+        env.mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }]);
+        let result = client.try_initialize(
+            &2,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &MilestoneVoting::Validators,
+        );
+        assert_eq!(result.is_ok(), signer == &project_launch);
+    }
+
+    // Unreleased funds are returned to the funding contract
+    assert_eq!(client.get_refund_address(&2), Some(project_launch));
+}
+
+#[test]
+fn test_deposits_are_pulled_and_refunds_capped_per_project() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, creator, token, validators, client) = setup_with_admin(&env);
+    let token_client = TokenClient::new(&env, &token);
+    client.initialize(
+        &2,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &MilestoneVoting::Validators,
+    );
+
+    // Deposits need the funder's authorization and move real tokens
+    let funder = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&funder, &1_000);
+    env.set_auths(&[]);
+    assert!(client.try_deposit(&1, &funder, &1_000).is_err());
+    env.mock_all_auths();
+    client.deposit(&1, &funder, &1_000);
+    assert!(client.try_deposit(&1, &funder, &1).is_err());
+    fund(&env, &client, 2, &token, 5_000);
+    assert_eq!(token_client.balance(&client.address), 6_000);
+    assert_eq!(client.get_escrow(&1).total_deposited, 1_000);
+
+    // Only the admin chooses where an escrow opened without ProjectLaunch refunds to
+    let backers = Address::generate(&env);
+    let args: Vec<Val> = (1u64, backers.clone()).into_val(&env);
+    env.mock_auths(&[MockAuth {
+        address: &creator,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_refund_address",
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_refund_address(&1, &backers).is_err());
+    env.mock_all_auths();
+    client.set_refund_address(&1, &backers);
+    assert!(client.try_set_refund_address(&1, &creator).is_err());
+
+    // A missed milestone refunds what project 1 funded, never project 2's deposit
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.create_milestone(&1, &hash, &1_000, &2_000);
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(client.trigger_refund(&1), 1_000);
+    assert_eq!(client.get_refunded_amount(&1), 1_000);
+    assert_eq!(client.get_available_balance(&1), 0);
+    assert!(client.try_trigger_refund(&1).is_err());
+    assert_eq!(token_client.balance(&backers), 1_000);
+    assert_eq!(token_client.balance(&client.address), 5_000);
+    assert_eq!(client.get_available_balance(&2), 5_000);
+}
+
+#[test]
+fn test_escrow_defaults_when_milestones_do_not_cover_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000);
+    let (_, creator, token, validators, client) = setup_with_admin(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let backers = Address::generate(&env);
+    for project_id in 2..5u64 {
+        client.initialize(
+            &project_id,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &MilestoneVoting::Validators,
+        );
+        fund(&env, &client, project_id, &token, 5_000);
+        client.set_refund_address(&project_id, &backers);
+    }
+    let setup_deadline = 1000 + MILESTONE_SETUP_PERIOD;
+    assert_eq!(client.get_setup_deadline(&2), setup_deadline);
+
+    // Milestone deadlines are bounded from when the escrow opened
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let result =
+        client.try_create_milestone(&2, &hash, &2_000, &(1000 + MAX_MILESTONE_DEADLINE + 1));
+    assert!(result.is_err());
+    let due = 1000 + MAX_MILESTONE_DEADLINE;
+    client.create_milestone(&2, &hash, &2_000, &due);
+    client.create_milestone(&4, &hash, &5_000, &due);
+    assert!(client.try_trigger_refund(&2).is_err());
+
+    // Project 2 is only partly covered and project 3 has no milestones at all
+    env.ledger().set_timestamp(setup_deadline + 1);
+    assert_eq!(client.trigger_refund(&2), 5_000);
+    assert_eq!(client.trigger_refund(&3), 5_000);
+    assert_eq!(token_client.balance(&backers), 10_000);
+
+    // A fully covered escrow only defaults on its milestones
+    assert!(client.try_trigger_refund(&4).is_err());
+}
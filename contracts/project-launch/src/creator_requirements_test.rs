@@ -0,0 +1,110 @@
+use crate::{CreatorRequirements, ProjectLaunch, ProjectLaunchClient};
+use identity::{IdentityContract, IdentityContractClient};
+use reputation::{ReputationContract, ReputationContractClient};
+use shared::constants::{MIN_FUNDING_GOAL, MIN_PROJECT_DURATION, REPUTATION_START};
+use shared::errors::Error;
+use shared::types::Jurisdiction;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, Vec,
+};
+
+fn create_project(
+    env: &Env,
+    launch: &ProjectLaunchClient,
+    creator: &Address,
+) -> Result<u64, Error> {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    match launch.try_create_project(
+        creator,
+        &MIN_FUNDING_GOAL,
+        &(1000000 + MIN_PROJECT_DURATION + 86400),
+        &token,
+        &Bytes::from_slice(env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    ) {
+        Ok(Ok(project_id)) => Ok(project_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected result"),
+    }
+}
+
+#[test]
+fn test_creator_requirements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let requirements = CreatorRequirements {
+        jurisdictions: Vec::from_array(&env, [Jurisdiction::UnitedStates]),
+        min_reputation: Some(REPUTATION_START + 50),
+        max_active_projects: Some(1),
+    };
+    launch.set_creator_requirements(&requirements);
+    assert_eq!(launch.get_creator_requirements(), Some(requirements));
+
+    // Gates fail safe while the identity and reputation contracts are unset
+    let creator = Address::generate(&env);
+    assert_eq!(
+        create_project(&env, &launch, &creator),
+        Err(Error::IdentityNotVerified)
+    );
+
+    let identity_id = env.register_contract(None, IdentityContract);
+    let identity = IdentityContractClient::new(&env, &identity_id);
+    identity.initialize(&admin);
+    launch.set_identity_contract(&identity_id);
+    identity.verify_identity(
+        &creator,
+        &Jurisdiction::UnitedStates,
+        &Bytes::from_slice(&env, &[1, 2, 3]),
+        &Bytes::from_slice(&env, &[0]),
+    );
+    assert_eq!(
+        create_project(&env, &launch, &creator),
+        Err(Error::Unauthorized)
+    );
+
+    let reputation_id = env.register_contract(None, ReputationContract);
+    let reputation = ReputationContractClient::new(&env, &reputation_id);
+    reputation.initialize(&admin);
+    launch.set_reputation_contract(&reputation_id);
+    reputation.register_user(&creator);
+    assert_eq!(
+        create_project(&env, &launch, &creator),
+        Err(Error::Unauthorized)
+    );
+
+    // A verified, reputable creator may run one active project at a time
+    reputation.update_score(&creator, &50);
+    let project_id = create_project(&env, &launch, &creator).unwrap();
+    assert_eq!(launch.get_active_project_count(&creator), 1);
+    assert_eq!(
+        create_project(&env, &launch, &creator),
+        Err(Error::InvalidInput)
+    );
+
+    launch.cancel_project(&project_id, &creator);
+    assert_eq!(launch.get_active_project_count(&creator), 0);
+    create_project(&env, &launch, &creator).unwrap();
+
+    // Unverified creators are rejected even with a good reputation
+    let unverified = Address::generate(&env);
+    reputation.register_user(&unverified);
+    reputation.update_score(&unverified, &50);
+    assert_eq!(
+        create_project(&env, &launch, &unverified),
+        Err(Error::IdentityNotVerified)
+    );
+}
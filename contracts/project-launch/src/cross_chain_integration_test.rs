@@ -79,6 +79,10 @@ fn test_cross_chain_deposit_and_fund() {
         &weth_issuer,
         &metadata_hash,
         &None,
+        &None,
+        &None,
+        &None,
+        &None,
     );
 
     // 5. User bridges WETH
@@ -111,9 +115,14 @@ fn test_cross_chain_deposit_and_fund() {
 
     // 6. User contributes WETH to project
     let contribution = 10_000_000_000;
-    let res = launch
-        .mock_all_auths()
-        .try_contribute(&project_id, &user, &contribution);
+    let res = launch.mock_all_auths().try_contribute(
+        &project_id,
+        &user,
+        &contribution,
+        &None,
+        &None,
+        &None,
+    );
     assert_eq!(res, Ok(Ok(())));
 
     assert_eq!(token_client.balance(&user), bridge_amount - contribution);
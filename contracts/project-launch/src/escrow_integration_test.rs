@@ -0,0 +1,684 @@
+use crate::{FundingMode, ProjectLaunch, ProjectLaunchClient, ProjectStatus};
+use escrow::EscrowContract;
+use shared::constants::{
+    DEFAULT_PLATFORM_FEE, MAX_PLATFORM_FEE, MIN_FUNDING_GOAL, MIN_PROJECT_DURATION,
+};
+use shared::types::{FeeConfig, MilestoneStatus, MilestoneVoting};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, Env, Vec,
+};
+
+fn create_token_contract<'a>(
+    env: &'a Env,
+    admin: &'a Address,
+) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    (
+        TokenClient::new(env, &sac.address()),
+        StellarAssetClient::new(env, &sac.address()),
+    )
+}
+
+fn create_validators(env: &Env) -> Vec<Address> {
+    let mut validators = Vec::new(env);
+    validators.push_back(Address::generate(env));
+    validators.push_back(Address::generate(env));
+    validators.push_back(Address::generate(env));
+    validators
+}
+
+#[test]
+fn test_finalize_funding_hands_off_to_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // 1. Deploy Project Launch and Escrow
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+    assert_eq!(launch.get_escrow_contract(), Some(escrow_id.clone()));
+
+    // Milestone validators come from admin config, not from the creator
+    let validators = create_validators(&env);
+    assert!(launch.try_set_validator_config(&validators, &5000).is_err());
+    launch.set_validator_config(&validators, &6700);
+
+    // 2. Create and fully fund a project
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let metadata_hash = Bytes::from_slice(&env, b"QmHash123");
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    token_admin_client.mint(&contributor, &MIN_FUNDING_GOAL);
+    launch.contribute(
+        &project_id,
+        &contributor,
+        &MIN_FUNDING_GOAL,
+        &None,
+        &None,
+        &None,
+    );
+
+    // 3. Finalizing before the deadline is rejected
+    let result = launch.try_finalize_funding(&project_id);
+    assert!(result.is_err());
+
+    // 4. Anyone may finalize after the deadline, without the creator's signature
+    env.ledger().set_timestamp(deadline + 1);
+    env.set_auths(&[]);
+    let moved = launch.finalize_funding(&project_id);
+    assert_eq!(moved, MIN_FUNDING_GOAL);
+
+    let project = launch.get_project(&project_id);
+    assert_eq!(project.status, ProjectStatus::Completed);
+    assert!(launch.is_escrow_funded(&project_id));
+
+    assert_eq!(token_client.balance(&launch.address), 0);
+    assert_eq!(token_client.balance(&escrow_id), MIN_FUNDING_GOAL);
+
+    let escrow_info = escrow.get_escrow(&project_id);
+    assert_eq!(escrow_info.creator, creator);
+    assert_eq!(escrow_info.token, token_client.address);
+    assert_eq!(escrow_info.total_deposited, MIN_FUNDING_GOAL);
+    assert_eq!(escrow_info.validators, validators);
+
+    // 5. Funds cannot be handed off twice
+    let result = launch.try_finalize_funding(&project_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_finalize_funding_requires_goal_and_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let metadata_hash = Bytes::from_slice(&env, b"QmHash123");
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    token_admin_client.mint(&contributor, &MIN_FUNDING_GOAL);
+    launch.contribute(
+        &project_id,
+        &contributor,
+        &(MIN_FUNDING_GOAL / 2),
+        &None,
+        &None,
+        &None,
+    );
+
+    env.ledger().set_timestamp(deadline + 1);
+
+    // Goal not reached
+    let result = launch.try_finalize_funding(&project_id);
+    assert!(result.is_err());
+
+    // Goal reached on a second project but no escrow configured
+    env.ledger().set_timestamp(1000000);
+    let funded_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    token_admin_client.mint(&contributor, &MIN_FUNDING_GOAL);
+    launch.contribute(
+        &funded_id,
+        &contributor,
+        &MIN_FUNDING_GOAL,
+        &None,
+        &None,
+        &None,
+    );
+    env.ledger().set_timestamp(deadline + 1);
+
+    let result = launch.try_finalize_funding(&funded_id);
+    assert!(result.is_err());
+    assert_eq!(launch.get_project(&funded_id).status, ProjectStatus::Active);
+    assert!(!launch.is_escrow_funded(&funded_id));
+}
+
+#[test]
+fn test_finalize_flexible_project_below_goal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+    launch.set_validator_config(&create_validators(&env), &6700);
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let metadata_hash = Bytes::from_slice(&env, b"QmHash123");
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some(FundingMode::Flexible),
+    );
+
+    let raised = MIN_FUNDING_GOAL / 4;
+    token_admin_client.mint(&contributor, &raised);
+    launch.contribute(&project_id, &contributor, &raised, &None, &None, &None);
+
+    // Whatever was raised is released to escrow
+    env.ledger().set_timestamp(deadline + 1);
+    let moved = launch.finalize_funding(&project_id);
+    assert_eq!(moved, raised);
+    assert_eq!(token_client.balance(&escrow_id), raised);
+    assert_eq!(
+        launch.get_project(&project_id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_finalize_funding_collects_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+    launch.set_validator_config(&create_validators(&env), &6700);
+
+    // Fees above MAX_PLATFORM_FEE are rejected, including the flexible-funding premium
+    let fee_recipient = Address::generate(&env);
+    let premium = 250;
+    let config = FeeConfig {
+        platform_fee: MAX_PLATFORM_FEE + 1,
+        creator_fee: 0,
+        fee_recipient: fee_recipient.clone(),
+        flexible_fee_premium: premium,
+    };
+    assert!(launch.try_set_fee_config(&config).is_err());
+    let result = launch.try_set_fee_config(&FeeConfig {
+        platform_fee: MAX_PLATFORM_FEE - premium + 1,
+        ..config.clone()
+    });
+    assert!(result.is_err());
+    launch.set_fee_config(&FeeConfig {
+        platform_fee: DEFAULT_PLATFORM_FEE,
+        ..config.clone()
+    });
+    escrow.set_fee_config(&FeeConfig {
+        platform_fee: DEFAULT_PLATFORM_FEE,
+        ..config
+    });
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let metadata_hash = Bytes::from_slice(&env, b"QmHash123");
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let funded_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    let flexible_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some(FundingMode::Flexible),
+    );
+
+    token_admin_client.mint(&contributor, &(MIN_FUNDING_GOAL * 2));
+    launch.contribute(
+        &funded_id,
+        &contributor,
+        &MIN_FUNDING_GOAL,
+        &None,
+        &None,
+        &None,
+    );
+    launch.contribute(
+        &flexible_id,
+        &contributor,
+        &(MIN_FUNDING_GOAL / 2),
+        &None,
+        &None,
+        &None,
+    );
+
+    env.ledger().set_timestamp(deadline + 1);
+
+    // Goal met: standard platform fee
+    let fee = MIN_FUNDING_GOAL * DEFAULT_PLATFORM_FEE as i128 / 10000;
+    let moved = launch.finalize_funding(&funded_id);
+    assert_eq!(moved, MIN_FUNDING_GOAL - fee);
+    assert_eq!(token_client.balance(&fee_recipient), fee);
+
+    // Flexible project below goal: fee plus premium
+    let raised = MIN_FUNDING_GOAL / 2;
+    let premium_fee = raised * (DEFAULT_PLATFORM_FEE + premium) as i128 / 10000;
+    let moved = launch.finalize_funding(&flexible_id);
+    assert_eq!(moved, raised - premium_fee);
+    assert_eq!(token_client.balance(&fee_recipient), fee + premium_fee);
+    assert_eq!(
+        launch.get_fees_collected(&token_client.address),
+        fee + premium_fee
+    );
+
+    // Milestone releases of launch-funded escrows are not charged a second fee
+    let validators = escrow.get_escrow(&funded_id).validators;
+    let hash = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let milestone_amount = MIN_FUNDING_GOAL / 2;
+    escrow.create_milestone(&funded_id, &hash, &milestone_amount, &(deadline + 86400));
+    escrow.submit_milestone(&funded_id, &0, &hash);
+    escrow.vote_milestone(&funded_id, &0, &validators.get(0).unwrap(), &true);
+    escrow.vote_milestone(&funded_id, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(token_client.balance(&creator), milestone_amount);
+    assert_eq!(token_client.balance(&fee_recipient), fee + premium_fee);
+    assert_eq!(escrow.get_fees_collected(&token_client.address), 0);
+}
+
+#[test]
+fn test_referral_rewards_paid_from_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+    launch.set_validator_config(&create_validators(&env), &6700);
+
+    let fee_recipient = Address::generate(&env);
+    launch.set_fee_config(&FeeConfig {
+        platform_fee: DEFAULT_PLATFORM_FEE,
+        creator_fee: 0,
+        fee_recipient: fee_recipient.clone(),
+        flexible_fee_premium: 0,
+    });
+    assert!(launch.try_set_referral_reward_bps(&10001).is_err());
+    launch.set_referral_reward_bps(&2000);
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let metadata_hash = Bytes::from_slice(&env, b"QmHash123");
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &metadata_hash,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    let referrer = Address::generate(&env);
+    let referred = Address::generate(&env);
+    let direct = Address::generate(&env);
+    let half = MIN_FUNDING_GOAL / 2;
+    token_admin_client.mint(&referred, &half);
+    token_admin_client.mint(&direct, &half);
+
+    // Contributors cannot refer themselves
+    let result = launch.try_contribute(
+        &project_id,
+        &referred,
+        &half,
+        &None,
+        &None,
+        &Some(referred.clone()),
+    );
+    assert!(result.is_err());
+
+    // The first referrer is credited with later contributions too
+    launch.contribute(
+        &project_id,
+        &referred,
+        &(half / 2),
+        &None,
+        &None,
+        &Some(referrer.clone()),
+    );
+    launch.contribute(&project_id, &referred, &(half / 2), &None, &None, &None);
+    launch.contribute(&project_id, &direct, &half, &None, &None, &None);
+    assert_eq!(
+        launch.get_referrer(&project_id, &referred),
+        Some(referrer.clone())
+    );
+    assert_eq!(launch.get_referral_total(&project_id, &referrer), half);
+
+    // Nothing to claim before the fee is taken
+    assert!(launch
+        .try_claim_referral_reward(&project_id, &referrer)
+        .is_err());
+
+    env.ledger().set_timestamp(deadline + 1);
+    launch.finalize_funding(&project_id);
+
+    // Referrers share 20% of the fee on the referred half of the raise
+    let fee = MIN_FUNDING_GOAL * DEFAULT_PLATFORM_FEE as i128 / 10000;
+    let pool = fee * 2000 / 10000 / 2;
+    assert_eq!(launch.claim_referral_reward(&project_id, &referrer), pool);
+    assert_eq!(token_client.balance(&referrer), pool);
+    assert_eq!(token_client.balance(&fee_recipient), fee - pool);
+    assert!(launch
+        .try_claim_referral_reward(&project_id, &referrer)
+        .is_err());
+}
+
+#[test]
+fn test_backers_reclaim_escrow_after_missed_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+    launch.set_validator_config(&create_validators(&env), &6700);
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let backer = Address::generate(&env);
+    let other_backer = Address::generate(&env);
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Backers fund the project 3:1
+    token_admin_client.mint(&backer, &(MIN_FUNDING_GOAL * 3 / 4));
+    token_admin_client.mint(&other_backer, &(MIN_FUNDING_GOAL / 4));
+    launch.contribute(
+        &project_id,
+        &backer,
+        &(MIN_FUNDING_GOAL * 3 / 4),
+        &None,
+        &None,
+        &None,
+    );
+    launch.contribute(
+        &project_id,
+        &other_backer,
+        &(MIN_FUNDING_GOAL / 4),
+        &None,
+        &None,
+        &None,
+    );
+
+    env.ledger().set_timestamp(deadline + 1);
+    launch.finalize_funding(&project_id);
+    assert_eq!(
+        escrow.get_refund_address(&project_id),
+        Some(launch_id.clone())
+    );
+
+    // The first milestone is released; the second is never delivered
+    let validators = escrow.get_escrow(&project_id).validators;
+    let hash = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let milestone_due = deadline + 86400;
+    escrow.create_milestone(&project_id, &hash, &(MIN_FUNDING_GOAL / 2), &milestone_due);
+    escrow.create_milestone(&project_id, &hash, &(MIN_FUNDING_GOAL / 2), &milestone_due);
+    escrow.submit_milestone(&project_id, &0, &hash);
+    escrow.vote_milestone(&project_id, &0, &validators.get(0).unwrap(), &true);
+    escrow.vote_milestone(&project_id, &0, &validators.get(1).unwrap(), &true);
+
+    // Nothing can be reclaimed before a milestone is missed
+    assert!(launch.try_reclaim_escrow(&project_id).is_err());
+    assert!(launch
+        .try_claim_escrow_refund(&project_id, &backer)
+        .is_err());
+
+    env.ledger().set_timestamp(milestone_due + 1);
+    assert!(escrow.try_submit_milestone(&project_id, &1, &hash).is_err());
+
+    // A refund triggered on the escrow directly is picked up when reclaiming
+    assert_eq!(escrow.trigger_refund(&project_id), MIN_FUNDING_GOAL / 2);
+    assert_eq!(launch.reclaim_escrow(&project_id), MIN_FUNDING_GOAL / 2);
+    assert!(launch.try_reclaim_escrow(&project_id).is_err());
+    assert_eq!(escrow.get_available_balance(&project_id), 0);
+
+    // Backers share the remainder pro rata
+    assert_eq!(
+        launch.claim_escrow_refund(&project_id, &backer),
+        MIN_FUNDING_GOAL * 3 / 8
+    );
+    assert_eq!(
+        launch.claim_escrow_refund(&project_id, &other_backer),
+        MIN_FUNDING_GOAL / 8
+    );
+    assert!(launch
+        .try_claim_escrow_refund(&project_id, &backer)
+        .is_err());
+    assert_eq!(token_client.balance(&backer), MIN_FUNDING_GOAL * 3 / 8);
+}
+
+#[test]
+fn test_backer_weighted_milestone_voting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let escrow_id = env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&env, &escrow_id);
+    escrow.initialize_admin(&admin);
+    escrow.set_project_launch(&launch_id);
+    launch.set_escrow_contract(&escrow_id);
+
+    let token_admin = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let creator = Address::generate(&env);
+    let backer = Address::generate(&env);
+    let other_backer = Address::generate(&env);
+    env.ledger().set_timestamp(1000000);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &token_client.address,
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Backers fund the project 3:1
+    token_admin_client.mint(&backer, &(MIN_FUNDING_GOAL * 3 / 4));
+    token_admin_client.mint(&other_backer, &(MIN_FUNDING_GOAL / 4));
+    launch.contribute(
+        &project_id,
+        &backer,
+        &(MIN_FUNDING_GOAL * 3 / 4),
+        &None,
+        &None,
+        &None,
+    );
+    launch.contribute(
+        &project_id,
+        &other_backer,
+        &(MIN_FUNDING_GOAL / 4),
+        &None,
+        &None,
+        &None,
+    );
+
+    // No validators are needed when backers vote
+    launch.set_backer_voting(&project_id, &true);
+    assert!(launch.get_backer_voting(&project_id));
+    env.ledger().set_timestamp(deadline + 1);
+    launch.finalize_funding(&project_id);
+    assert_eq!(
+        escrow.get_voting(&project_id),
+        MilestoneVoting::Backers(launch_id.clone())
+    );
+    assert_eq!(launch.get_total_contributed(&project_id), MIN_FUNDING_GOAL);
+
+    let hash = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+    let milestone_due = deadline + 86400;
+    escrow.create_milestone(&project_id, &hash, &(MIN_FUNDING_GOAL / 2), &milestone_due);
+    escrow.create_milestone(&project_id, &hash, &(MIN_FUNDING_GOAL / 2), &milestone_due);
+    escrow.submit_milestone(&project_id, &0, &hash);
+    escrow.submit_milestone(&project_id, &1, &hash);
+
+    // Only backers may vote, once each
+    let outsider = Address::generate(&env);
+    assert!(escrow
+        .try_vote_milestone(&project_id, &0, &outsider, &true)
+        .is_err());
+
+    // 25% approval is below MILESTONE_APPROVAL_THRESHOLD
+    escrow.vote_milestone(&project_id, &0, &other_backer, &true);
+    assert!(escrow
+        .try_vote_milestone(&project_id, &0, &other_backer, &true)
+        .is_err());
+    assert_eq!(
+        escrow.get_milestone(&project_id, &0).status,
+        MilestoneStatus::Submitted
+    );
+    assert_eq!(
+        escrow.get_backer_tally(&project_id, &0),
+        (MIN_FUNDING_GOAL / 4, 0)
+    );
+
+    escrow.vote_milestone(&project_id, &0, &backer, &true);
+    assert_eq!(
+        escrow.get_milestone(&project_id, &0).status,
+        MilestoneStatus::Approved
+    );
+    assert_eq!(token_client.balance(&creator), MIN_FUNDING_GOAL / 2);
+
+    // A 75% rejection cannot be outvoted
+    escrow.vote_milestone(&project_id, &1, &backer, &false);
+    assert_eq!(
+        escrow.get_milestone(&project_id, &1).status,
+        MilestoneStatus::Rejected
+    );
+}
@@ -0,0 +1,244 @@
+use crate::{ProjectLaunch, ProjectLaunchClient};
+use shared::constants::{
+    AMENDMENT_OBJECTION_PERIOD, DEFAULT_WITHDRAWAL_LOCK_PERIOD, MIN_CONTRIBUTION, MIN_FUNDING_GOAL,
+    MIN_PROJECT_DURATION,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, Env, IntoVal, Val, Vec,
+};
+
+#[test]
+fn test_matching_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    let token_admin = StellarAssetClient::new(&env, &sac.address());
+
+    let creator = Address::generate(&env);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + DEFAULT_WITHDRAWAL_LOCK_PERIOD;
+    let create = || {
+        launch.create_project(
+            &creator,
+            &MIN_FUNDING_GOAL,
+            &deadline,
+            &sac.address(),
+            &Bytes::from_slice(&env, b"QmHash123"),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let project_id = create();
+    let other_project_id = create();
+
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &(MIN_CONTRIBUTION * 10));
+
+    // Sponsors cannot open a pool without the admin
+    let args: Vec<Val> = (
+        sponsor.clone(),
+        sac.address(),
+        MIN_CONTRIBUTION * 3,
+        10000u32,
+        None::<i128>,
+        None::<u64>,
+    )
+        .into_val(&env);
+    env.mock_auths(&[MockAuth {
+        address: &sponsor,
+        invoke: &MockAuthInvoke {
+            contract: &launch_id,
+            fn_name: "create_matching_pool",
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+    let result = launch.try_create_matching_pool(
+        &sponsor,
+        &sac.address(),
+        &(MIN_CONTRIBUTION * 3),
+        &10000,
+        &None,
+        &None,
+    );
+    assert!(result.is_err());
+    env.mock_all_auths();
+
+    let result = launch.try_create_matching_pool(
+        &sponsor,
+        &sac.address(),
+        &(MIN_CONTRIBUTION * 3),
+        &0,
+        &None,
+        &None,
+    );
+    assert!(result.is_err());
+
+    // 1:1 match, at most 2 units per project
+    let pool_id = launch.create_matching_pool(
+        &sponsor,
+        &sac.address(),
+        &(MIN_CONTRIBUTION * 3),
+        &10000,
+        &Some(MIN_CONTRIBUTION * 2),
+        &None,
+    );
+    // Pool restricted to another project
+    let other_pool_id = launch.create_matching_pool(
+        &sponsor,
+        &sac.address(),
+        &MIN_CONTRIBUTION,
+        &10000,
+        &None,
+        &Some(other_project_id),
+    );
+    assert_eq!(token.balance(&sponsor), MIN_CONTRIBUTION * 6);
+    assert_eq!(launch.get_open_matching_pools().len(), 2);
+
+    let backer = Address::generate(&env);
+    let other_backer = Address::generate(&env);
+    token_admin.mint(&backer, &(MIN_CONTRIBUTION * 5));
+    token_admin.mint(&other_backer, &(MIN_CONTRIBUTION * 5));
+
+    launch.contribute(&project_id, &backer, &MIN_CONTRIBUTION, &None, &None, &None);
+    assert_eq!(
+        launch.get_project(&project_id).total_raised,
+        MIN_CONTRIBUTION * 2
+    );
+
+    // The per-project cap limits the second match
+    launch.contribute(
+        &project_id,
+        &other_backer,
+        &(MIN_CONTRIBUTION * 2),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        launch.get_project(&project_id).total_raised,
+        MIN_CONTRIBUTION * 5
+    );
+    assert_eq!(
+        launch.get_project_matched(&project_id, &pool_id),
+        MIN_CONTRIBUTION * 2
+    );
+    assert_eq!(launch.get_project_matched(&project_id, &other_pool_id), 0);
+    assert_eq!(
+        launch.get_matching_pool(&pool_id).remaining,
+        MIN_CONTRIBUTION
+    );
+
+    // Withdrawals hand the withdrawing backer's own match back to the pool
+    launch.withdraw_contribution(&project_id, &backer, &MIN_CONTRIBUTION, &None);
+    assert_eq!(
+        launch.get_project(&project_id).total_raised,
+        MIN_CONTRIBUTION * 3
+    );
+    assert_eq!(
+        launch.get_matching_pool(&pool_id).remaining,
+        MIN_CONTRIBUTION * 2
+    );
+
+    // Half of a capped backer's contribution only releases half of their match
+    launch.withdraw_contribution(&project_id, &other_backer, &MIN_CONTRIBUTION, &None);
+    assert_eq!(
+        launch.get_project(&project_id).total_raised,
+        MIN_CONTRIBUTION * 3 / 2
+    );
+    assert_eq!(
+        launch.get_project_matched(&project_id, &pool_id),
+        MIN_CONTRIBUTION / 2
+    );
+
+    // Matched funds return to the sponsor when the project fails
+    env.ledger().set_timestamp(deadline + 1);
+    launch.mark_project_failed(&project_id);
+    assert_eq!(
+        launch.return_matching_funds(&project_id),
+        MIN_CONTRIBUTION / 2
+    );
+    assert!(launch.try_return_matching_funds(&project_id).is_err());
+    launch.refund_contributor(&project_id, &other_backer);
+    assert_eq!(token.balance(&other_backer), MIN_CONTRIBUTION * 5);
+
+    assert_eq!(
+        launch.close_matching_pool(&pool_id),
+        MIN_CONTRIBUTION * 5 / 2
+    );
+    launch.close_matching_pool(&other_pool_id);
+    assert_eq!(token.balance(&sponsor), MIN_CONTRIBUTION * 10);
+    assert_eq!(launch.get_open_matching_pools().len(), 0);
+}
+
+#[test]
+fn test_amendment_quorum_excludes_matched_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token_admin = StellarAssetClient::new(&env, &sac.address());
+
+    let creator = Address::generate(&env);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + DEFAULT_WITHDRAWAL_LOCK_PERIOD;
+    let project_id = launch.create_project(
+        &creator,
+        &(MIN_FUNDING_GOAL * 2),
+        &deadline,
+        &sac.address(),
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &(MIN_CONTRIBUTION * 10));
+    launch.create_matching_pool(
+        &sponsor,
+        &sac.address(),
+        &(MIN_CONTRIBUTION * 10),
+        &10000,
+        &None,
+        &Some(project_id),
+    );
+
+    // A 1:1 match doubles total_raised, but only backers approve
+    let backer = Address::generate(&env);
+    token_admin.mint(&backer, &MIN_CONTRIBUTION);
+    launch.contribute(&project_id, &backer, &MIN_CONTRIBUTION, &None, &None, &None);
+    assert_eq!(
+        launch.get_project(&project_id).total_raised,
+        MIN_CONTRIBUTION * 2
+    );
+
+    launch.propose_amendment(&project_id, &None, &Some(MIN_FUNDING_GOAL));
+    launch.approve_amendment(&project_id, &backer);
+    env.ledger()
+        .set_timestamp(1000000 + AMENDMENT_OBJECTION_PERIOD);
+    launch.execute_amendment(&project_id);
+    assert_eq!(
+        launch.get_project(&project_id).funding_goal,
+        MIN_FUNDING_GOAL
+    );
+}
@@ -0,0 +1,425 @@
+use crate::{ProjectLaunch, ProjectLaunchClient, ProjectStatus};
+use escrow::EscrowContract;
+use oracle_network::{OracleNetwork, OracleNetworkClient};
+use shared::constants::{
+    DEFAULT_WITHDRAWAL_LOCK_PERIOD, MIN_FUNDING_GOAL, MIN_PROJECT_DURATION, ORACLE_PRICE_MAX_AGE,
+};
+use shared::types::{OracleFeedConfig, OracleFeedType};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, BytesN, Env, IntoVal, String, Val, Vec,
+};
+
+fn create_token_contract<'a>(
+    env: &Env,
+    admin: &Address,
+) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    (
+        TokenClient::new(env, &sac.address()),
+        StellarAssetClient::new(env, &sac.address()),
+    )
+}
+
+struct Setup<'a> {
+    env: Env,
+    launch: ProjectLaunchClient<'a>,
+    admin: Address,
+    creator: Address,
+    goal_token: TokenClient<'a>,
+    goal_token_admin: StellarAssetClient<'a>,
+    alt_token: TokenClient<'a>,
+    alt_token_admin: StellarAssetClient<'a>,
+    project_id: u64,
+    deadline: u64,
+}
+
+/// Deploys a project accepting an alternative token priced at 2 goal tokens (4 decimals)
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let oracle_id = env.register_contract(None, OracleNetwork);
+    let oracle = OracleNetworkClient::new(&env, &oracle_id);
+    oracle.initialize(&admin);
+    let reporter = Address::generate(&env);
+    let mut oracles = Vec::new(&env);
+    oracles.push_back(reporter.clone());
+    oracle.create_feed(
+        &admin,
+        &symbol_short!("ALT_GOAL"),
+        &OracleFeedConfig {
+            feed_type: OracleFeedType::Price,
+            description: String::from_str(&env, "ALT priced in goal token"),
+            decimals: 4,
+            heartbeat_seconds: 0,
+            deviation_bps: 0,
+            min_oracles: 1,
+            max_oracles: 1,
+            reward_per_submission: 0,
+        },
+        &oracles,
+    );
+    oracle.submit(&symbol_short!("ALT_GOAL"), &20_000, &1000000, &reporter);
+    launch.set_oracle_contract(&oracle_id);
+    assert_eq!(launch.get_oracle_contract(), Some(oracle_id));
+
+    let token_admin = Address::generate(&env);
+    let (goal_token, goal_token_admin) = create_token_contract(&env, &token_admin);
+    let (alt_token, alt_token_admin) = create_token_contract(&env, &token_admin);
+
+    let creator = Address::generate(&env);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + DEFAULT_WITHDRAWAL_LOCK_PERIOD;
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &goal_token.address,
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    launch.add_accepted_token(
+        &project_id,
+        &alt_token.address,
+        &symbol_short!("ALT_GOAL"),
+        &4,
+    );
+
+    Setup {
+        env,
+        launch,
+        admin,
+        creator,
+        goal_token,
+        goal_token_admin,
+        alt_token,
+        alt_token_admin,
+        project_id,
+        deadline,
+    }
+}
+
+#[test]
+fn test_contribute_with_accepted_token() {
+    let s = setup();
+    let contributor = Address::generate(&s.env);
+    s.alt_token_admin.mint(&contributor, &1_000_000_000);
+    s.goal_token_admin.mint(&contributor, &1_000_000_000);
+
+    // Creators cannot choose accepted tokens or their price feeds
+    let (other_token, other_token_admin) = create_token_contract(&s.env, &s.admin);
+    let args: Vec<Val> = (
+        s.project_id,
+        other_token.address.clone(),
+        symbol_short!("ALT_GOAL"),
+        4u32,
+    )
+        .into_val(&s.env);
+    s.env.mock_auths(&[MockAuth {
+        address: &s.creator,
+        invoke: &MockAuthInvoke {
+            contract: &s.launch.address,
+            fn_name: "add_accepted_token",
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+    let result = s.launch.try_add_accepted_token(
+        &s.project_id,
+        &other_token.address,
+        &symbol_short!("ALT_GOAL"),
+        &4,
+    );
+    assert!(result.is_err());
+    s.env.mock_all_auths();
+
+    // Duplicate or primary tokens cannot be added
+    let result = s.launch.try_add_accepted_token(
+        &s.project_id,
+        &s.alt_token.address,
+        &symbol_short!("ALT_GOAL"),
+        &4,
+    );
+    assert!(result.is_err());
+    let result = s.launch.try_add_accepted_token(
+        &s.project_id,
+        &s.goal_token.address,
+        &symbol_short!("ALT_GOAL"),
+        &4,
+    );
+    assert!(result.is_err());
+    assert_eq!(s.launch.get_accepted_tokens(&s.project_id).len(), 1);
+
+    // 10 ALT units are worth 20 goal-token units
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &100_000_000,
+        &None,
+        &Some(s.alt_token.address.clone()),
+        &None,
+    );
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &150_000_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    assert_eq!(s.alt_token.balance(&s.launch.address), 100_000_000);
+    assert_eq!(s.goal_token.balance(&s.launch.address), 150_000_000);
+    assert_eq!(
+        s.launch.get_user_contribution(&s.project_id, &contributor),
+        350_000_000
+    );
+    assert_eq!(
+        s.launch.get_project(&s.project_id).total_raised,
+        350_000_000
+    );
+
+    let record = s
+        .launch
+        .get_token_contribution(&s.project_id, &contributor, &s.alt_token.address);
+    assert_eq!(record.amount, 100_000_000);
+    assert_eq!(record.value, 200_000_000);
+
+    // Each token is withdrawn from what was paid in it
+    let result =
+        s.launch
+            .try_withdraw_contribution(&s.project_id, &contributor, &160_000_000, &None);
+    assert!(result.is_err());
+    s.launch
+        .withdraw_contribution(&s.project_id, &contributor, &150_000_000, &None);
+    assert_eq!(s.goal_token.balance(&s.launch.address), 0);
+
+    let alt = Some(s.alt_token.address.clone());
+    let result =
+        s.launch
+            .try_withdraw_contribution(&s.project_id, &contributor, &100_000_001, &alt);
+    assert!(result.is_err());
+    s.launch
+        .withdraw_contribution(&s.project_id, &contributor, &40_000_000, &alt);
+    assert_eq!(s.alt_token.balance(&s.launch.address), 60_000_000);
+    assert_eq!(
+        s.launch.get_user_contribution(&s.project_id, &contributor),
+        120_000_000
+    );
+    let record = s
+        .launch
+        .get_token_contribution(&s.project_id, &contributor, &s.alt_token.address);
+    assert_eq!(record.amount, 60_000_000);
+    assert_eq!(record.value, 120_000_000);
+    assert_eq!(
+        s.launch
+            .get_token_raised(&s.project_id, &s.alt_token.address)
+            .value,
+        120_000_000
+    );
+
+    // Tokens that were not accepted are rejected
+    other_token_admin.mint(&contributor, &100_000_000);
+    let result = s.launch.try_contribute(
+        &s.project_id,
+        &contributor,
+        &100_000_000,
+        &None,
+        &Some(other_token.address.clone()),
+        &None,
+    );
+    assert!(result.is_err());
+
+    // Stale prices are rejected
+    s.env
+        .ledger()
+        .set_timestamp(1000000 + ORACLE_PRICE_MAX_AGE + 1);
+    let result = s.launch.try_contribute(
+        &s.project_id,
+        &contributor,
+        &100_000_000,
+        &None,
+        &Some(s.alt_token.address.clone()),
+        &None,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_refund_returns_original_tokens() {
+    let s = setup();
+    let contributor = Address::generate(&s.env);
+    s.alt_token_admin.mint(&contributor, &100_000_000);
+    s.goal_token_admin.mint(&contributor, &150_000_000);
+
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &100_000_000,
+        &None,
+        &Some(s.alt_token.address.clone()),
+        &None,
+    );
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &150_000_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    s.launch.mark_project_failed(&s.project_id);
+    let refunded = s.launch.refund_contributor(&s.project_id, &contributor);
+
+    assert_eq!(refunded, 350_000_000);
+    assert_eq!(s.alt_token.balance(&contributor), 100_000_000);
+    assert_eq!(s.goal_token.balance(&contributor), 150_000_000);
+    assert_eq!(s.alt_token.balance(&s.launch.address), 0);
+}
+
+#[test]
+fn test_finalize_funding_splits_alternative_tokens() {
+    let s = setup();
+    let escrow_id = s.env.register_contract(None, EscrowContract);
+    let escrow = escrow::EscrowContractClient::new(&s.env, &escrow_id);
+    escrow.initialize_admin(&s.admin);
+    escrow.set_project_launch(&s.launch.address);
+    s.launch.set_escrow_contract(&escrow_id);
+    let mut validators = Vec::new(&s.env);
+    validators.push_back(Address::generate(&s.env));
+    validators.push_back(Address::generate(&s.env));
+    validators.push_back(Address::generate(&s.env));
+    s.launch.set_validator_config(&validators, &6700);
+
+    let contributor = Address::generate(&s.env);
+    let alt_amount = MIN_FUNDING_GOAL / 4;
+    s.alt_token_admin.mint(&contributor, &alt_amount);
+    s.goal_token_admin
+        .mint(&contributor, &(MIN_FUNDING_GOAL / 2));
+
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &alt_amount,
+        &None,
+        &Some(s.alt_token.address.clone()),
+        &None,
+    );
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &(MIN_FUNDING_GOAL / 2),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        s.launch.get_project(&s.project_id).total_raised,
+        MIN_FUNDING_GOAL
+    );
+
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    let moved = s.launch.finalize_funding(&s.project_id);
+
+    // Alternative tokens are held back until the goal-token escrow releases funds
+    assert_eq!(moved, MIN_FUNDING_GOAL / 2);
+    assert_eq!(s.goal_token.balance(&escrow_id), MIN_FUNDING_GOAL / 2);
+    assert_eq!(s.alt_token.balance(&s.creator), 0);
+    assert_eq!(s.alt_token.balance(&s.launch.address), alt_amount);
+    assert_eq!(
+        s.launch
+            .get_token_escrow(&s.project_id, &s.alt_token.address)
+            .unwrap()
+            .amount,
+        alt_amount
+    );
+    assert!(s
+        .launch
+        .try_release_token_escrow(&s.project_id, &s.alt_token.address)
+        .is_err());
+
+    // Releasing half of the escrow releases half of the alternative tokens
+    let hash = BytesN::from_array(&s.env, &[1u8; 32]);
+    let milestone_due = s.deadline + 86400;
+    escrow.create_milestone(
+        &s.project_id,
+        &hash,
+        &(MIN_FUNDING_GOAL / 4),
+        &milestone_due,
+    );
+    escrow.create_milestone(
+        &s.project_id,
+        &hash,
+        &(MIN_FUNDING_GOAL / 4),
+        &milestone_due,
+    );
+    escrow.submit_milestone(&s.project_id, &0, &hash);
+    escrow.vote_milestone(&s.project_id, &0, &validators.get(0).unwrap(), &true);
+    escrow.vote_milestone(&s.project_id, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(
+        s.launch
+            .release_token_escrow(&s.project_id, &s.alt_token.address),
+        alt_amount / 2
+    );
+    assert_eq!(s.alt_token.balance(&s.creator), alt_amount / 2);
+
+    // The second milestone is missed, so backers get the rest of both tokens back
+    s.env.ledger().set_timestamp(milestone_due + 1);
+    s.launch.reclaim_escrow(&s.project_id);
+    assert_eq!(
+        s.launch.claim_escrow_refund(&s.project_id, &contributor),
+        MIN_FUNDING_GOAL / 4
+    );
+    assert_eq!(s.alt_token.balance(&contributor), alt_amount / 2);
+    assert_eq!(s.alt_token.balance(&s.launch.address), 0);
+    assert!(s
+        .launch
+        .try_release_token_escrow(&s.project_id, &s.alt_token.address)
+        .is_err());
+}
+
+#[test]
+fn test_alternative_token_only_raise_is_refunded() {
+    let s = setup();
+    let contributor = Address::generate(&s.env);
+    let alt_amount = MIN_FUNDING_GOAL / 2;
+    s.alt_token_admin.mint(&contributor, &alt_amount);
+    s.launch.contribute(
+        &s.project_id,
+        &contributor,
+        &alt_amount,
+        &None,
+        &Some(s.alt_token.address.clone()),
+        &None,
+    );
+    assert_eq!(
+        s.launch.get_project(&s.project_id).total_raised,
+        MIN_FUNDING_GOAL
+    );
+
+    // Nothing can be escrowed in the goal token, so the project fails instead of completing
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    assert_eq!(
+        s.launch.finalize_project(&s.project_id),
+        ProjectStatus::Failed
+    );
+    assert!(s.launch.try_finalize_funding(&s.project_id).is_err());
+
+    s.launch.refund_contributor(&s.project_id, &contributor);
+    assert_eq!(s.alt_token.balance(&contributor), alt_amount);
+    assert_eq!(s.alt_token.balance(&s.launch.address), 0);
+}
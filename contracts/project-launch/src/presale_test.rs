@@ -0,0 +1,120 @@
+use crate::{PresaleRules, ProjectLaunch, ProjectLaunchClient};
+use reputation::{ReputationContract, ReputationContractClient};
+use shared::constants::{
+    MIN_CONTRIBUTION, MIN_FUNDING_GOAL, MIN_PROJECT_DURATION, REPUTATION_START,
+};
+use shared::types::BadgeType;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Bytes, Env, Vec,
+};
+
+#[test]
+fn test_presale_phase() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let reputation_id = env.register_contract(None, ReputationContract);
+    let reputation = ReputationContractClient::new(&env, &reputation_id);
+    reputation.initialize(&admin);
+    launch.set_reputation_contract(&reputation_id);
+
+    let token_admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = StellarAssetClient::new(&env, &sac.address());
+
+    let creator = Address::generate(&env);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + 86400;
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &sac.address(),
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Presale must end before the deadline
+    let presale_end = 1000000 + 86400;
+    let rules = PresaleRules {
+        min_reputation: Some(REPUTATION_START + 50),
+        required_badges: Vec::from_array(&env, [BadgeType::EarlyAdopter]),
+        max_per_address: Some(MIN_CONTRIBUTION * 2),
+    };
+    let result = launch.try_set_presale(&project_id, &deadline, &rules);
+    assert!(result.is_err());
+    launch.set_presale(&project_id, &presale_end, &rules);
+    assert_eq!(launch.get_project(&project_id).presale_ends_at, presale_end);
+    assert_eq!(launch.get_presale_rules(&project_id), Some(rules.clone()));
+
+    let listed = Address::generate(&env);
+    let reputable = Address::generate(&env);
+    let badged = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    for backer in [&listed, &reputable, &badged, &newcomer] {
+        token_admin_client.mint(backer, &(MIN_CONTRIBUTION * 3));
+    }
+
+    let mut allowlist = Vec::new(&env);
+    allowlist.push_back(listed.clone());
+    launch.set_presale_allowlist(&project_id, &allowlist, &true);
+
+    reputation.register_user(&reputable);
+    reputation.update_score(&reputable, &50);
+    reputation.register_user(&badged);
+    reputation.award_badge(&badged, &BadgeType::EarlyAdopter);
+    reputation.register_user(&newcomer);
+
+    // Allow-listed, reputable and badge-holding addresses may contribute early
+    for backer in [&listed, &reputable, &badged] {
+        assert!(launch.is_presale_eligible(&project_id, backer));
+        launch.contribute(&project_id, backer, &MIN_CONTRIBUTION, &None, &None, &None);
+    }
+    assert!(!launch.is_presale_eligible(&project_id, &newcomer));
+    let result = launch.try_contribute(
+        &project_id,
+        &newcomer,
+        &MIN_CONTRIBUTION,
+        &None,
+        &None,
+        &None,
+    );
+    assert!(result.is_err());
+
+    // The presale cap applies to the aggregated contribution
+    launch.contribute(&project_id, &listed, &MIN_CONTRIBUTION, &None, &None, &None);
+    let result =
+        launch.try_contribute(&project_id, &listed, &MIN_CONTRIBUTION, &None, &None, &None);
+    assert!(result.is_err());
+
+    // Once the public phase opens, anyone may contribute without the presale cap
+    env.ledger().set_timestamp(presale_end);
+    launch.contribute(
+        &project_id,
+        &newcomer,
+        &MIN_CONTRIBUTION,
+        &None,
+        &None,
+        &None,
+    );
+    launch.contribute(&project_id, &listed, &MIN_CONTRIBUTION, &None, &None, &None);
+    assert_eq!(
+        launch.get_user_contribution(&project_id, &listed),
+        MIN_CONTRIBUTION * 3
+    );
+
+    // The schedule cannot change after contributions arrive
+    let result = launch.try_set_presale(&project_id, &(presale_end + 86400), &rules);
+    assert!(result.is_err());
+}
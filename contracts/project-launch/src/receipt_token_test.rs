@@ -0,0 +1,104 @@
+use crate::{ProjectLaunch, ProjectLaunchClient};
+use shared::constants::{
+    DEFAULT_WITHDRAWAL_LOCK_PERIOD, MIN_CONTRIBUTION, MIN_FUNDING_GOAL, MIN_PROJECT_DURATION,
+};
+use shared::errors::Error;
+use soroban_sdk::{
+    testutils::{Address as _, IssuerFlags, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, Env,
+};
+
+#[test]
+fn test_receipt_tokens_track_contributions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000000);
+
+    let launch_id = env.register_contract(None, ProjectLaunch);
+    let launch = ProjectLaunchClient::new(&env, &launch_id);
+    let admin = Address::generate(&env);
+    launch.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let goal_sac = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let goal_token_admin = StellarAssetClient::new(&env, &goal_sac.address());
+
+    let creator = Address::generate(&env);
+    let deadline = 1000000 + MIN_PROJECT_DURATION + DEFAULT_WITHDRAWAL_LOCK_PERIOD;
+    let project_id = launch.create_project(
+        &creator,
+        &MIN_FUNDING_GOAL,
+        &deadline,
+        &goal_sac.address(),
+        &Bytes::from_slice(&env, b"QmHash123"),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    // The receipt asset must be administered by the launch contract
+    let receipt_sac = env.register_stellar_asset_contract_v2(creator.clone());
+    receipt_sac
+        .issuer()
+        .set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let receipt = TokenClient::new(&env, &receipt_sac.address());
+    let result = launch.try_set_receipt_token(&project_id, &receipt_sac.address());
+    assert!(result.is_err());
+    StellarAssetClient::new(&env, &receipt_sac.address()).set_admin(&launch_id);
+    launch.set_receipt_token(&project_id, &receipt_sac.address());
+    assert_eq!(
+        launch.get_receipt_token(&project_id),
+        Some(receipt_sac.address())
+    );
+
+    let backer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    goal_token_admin.mint(&backer, &(MIN_CONTRIBUTION * 4));
+    launch.contribute(
+        &project_id,
+        &backer,
+        &(MIN_CONTRIBUTION * 4),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(receipt.balance(&backer), MIN_CONTRIBUTION * 4);
+
+    // Receipts cannot be swapped once contributions have arrived
+    let result = launch.try_set_receipt_token(&project_id, &receipt_sac.address());
+    assert!(result.is_err());
+
+    // Receipts are transferable, but withdrawals need the receipts back
+    receipt.transfer(&backer, &buyer, &(MIN_CONTRIBUTION * 3));
+    let result =
+        launch.try_withdraw_contribution(&project_id, &backer, &(MIN_CONTRIBUTION * 2), &None);
+    assert!(result.is_err());
+    launch.withdraw_contribution(&project_id, &backer, &MIN_CONTRIBUTION, &None);
+    assert_eq!(receipt.balance(&backer), 0);
+    assert_eq!(
+        launch.get_user_contribution(&project_id, &backer),
+        MIN_CONTRIBUTION * 3
+    );
+
+    // Refunds need the receipts for the whole contribution, which are burned
+    receipt.transfer(&buyer, &backer, &(MIN_CONTRIBUTION * 2));
+    env.ledger().set_timestamp(deadline + 1);
+    launch.mark_project_failed(&project_id);
+    let result = launch.try_refund_contributor(&project_id, &backer);
+    assert_eq!(result, Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(launch.refund_batch(&project_id, &0, &10), 1);
+    assert!(!launch.is_refunded(&project_id, &backer));
+    assert_eq!(receipt.balance(&backer), MIN_CONTRIBUTION * 2);
+
+    receipt.transfer(&buyer, &backer, &MIN_CONTRIBUTION);
+    launch.refund_contributor(&project_id, &backer);
+    assert_eq!(receipt.balance(&backer), 0);
+    assert_eq!(receipt.balance(&buyer), 0);
+    assert_eq!(
+        TokenClient::new(&env, &goal_sac.address()).balance(&backer),
+        MIN_CONTRIBUTION * 4
+    );
+}
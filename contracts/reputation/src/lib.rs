@@ -3,6 +3,7 @@
 use shared::constants::{REPUTATION_MAX, REPUTATION_MIN, REPUTATION_START};
 use shared::errors::Error;
 use shared::events::{BADGE_EARNED, REPUTATION_UPDATED, USER_REGISTERED};
+pub use shared::types::{BadgeType, ReputationProfile};
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
 
 /// Storage keys for the reputation contract
@@ -17,39 +18,6 @@ pub enum DataKey {
     Initialized,
 }
 
-/// Badge types that can be awarded to users
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-#[contracttype]
-#[repr(u32)]
-pub enum BadgeType {
-    /// Awarded for making contributions to projects
-    Contributor = 0,
-    /// Awarded to verified creators
-    VerifiedCreator = 1,
-    /// Awarded to top investors by volume
-    TopInvestor = 2,
-    /// Awarded for early platform adoption
-    EarlyAdopter = 3,
-    /// Awarded for completing first project
-    FirstProject = 4,
-    /// Awarded for successful milestone completion
-    MilestoneAchiever = 5,
-    /// Awarded for governance participation
-    GovernanceParticipant = 6,
-}
-
-/// User reputation profile
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[contracttype]
-pub struct ReputationProfile {
-    /// User's address
-    pub user: Address,
-    /// Reputation score (clamped between REPUTATION_MIN and REPUTATION_MAX)
-    pub score: i128,
-    /// List of badges earned by the user
-    pub badges: Vec<BadgeType>,
-}
-
 #[contract]
 pub struct ReputationContract;
 
@@ -16,12 +16,63 @@ pub const MIN_PROJECT_DURATION: u64 = 86400;
 /// Maximum project duration (180 days in seconds)
 pub const MAX_PROJECT_DURATION: u64 = 15552000;
 
+/// Approximate number of ledgers closed per day (5 second ledgers)
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+/// TTL extension for contract instance storage (7 days)
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// TTL extension for persistent per-project storage (30 days)
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Maximum number of entries returned by a paginated query, keeping a page of projects and
+/// their index entries within the 40 ledger entries a transaction may read
+pub const MAX_PAGE_SIZE: u32 = 16;
+
+/// Number of consecutive project ids stored per status index entry
+pub const INDEX_BUCKET_SIZE: u64 = 64;
+
+/// Maximum number of status index entries scanned by one project listing
+pub const MAX_INDEX_BUCKETS_PER_PAGE: u64 = 4;
+
+/// Maximum number of open sponsor matching pools
+pub const MAX_MATCHING_POOLS: u32 = 20;
+
 /// Minimum contribution amount
 pub const MIN_CONTRIBUTION: i128 = 10_0000000; // 10 XLM
 
+/// Default window before a project deadline during which contributions cannot be withdrawn (48 hours)
+pub const DEFAULT_WITHDRAWAL_LOCK_PERIOD: u64 = 172800;
+
+/// Time backers have to object to a project amendment before it can take effect (24 hours)
+pub const AMENDMENT_OBJECTION_PERIOD: u64 = 86400;
+
+/// Time after which a pending amendment that was not executed lapses (7 days)
+pub const AMENDMENT_EXPIRY_PERIOD: u64 = 604800;
+
 /// Voting threshold for milestone approval (60%)
 pub const MILESTONE_APPROVAL_THRESHOLD: u32 = 6000;
 
+/// Time validators have to vote on a submitted milestone (7 days in seconds)
+pub const MILESTONE_VOTING_PERIOD: u64 = 604800;
+
+/// Share of validators that must vote for a lapsed milestone vote to be decided by its votes (50%)
+pub const MILESTONE_VOTE_QUORUM: u32 = 5000;
+
+/// Number of times a rejected milestone may be resubmitted
+pub const MAX_MILESTONE_RESUBMISSIONS: u32 = 3;
+
+/// Time after an escrow opens by which its milestones must cover the whole deposit (30 days)
+pub const MILESTONE_SETUP_PERIOD: u64 = 2592000;
+
+/// Latest a milestone deadline may fall after its escrow opens (365 days)
+pub const MAX_MILESTONE_DEADLINE: u64 = 31536000;
+
+/// Time allowed for each follow-up sub-proof tranche, counted from milestone approval (30 days)
+pub const SUB_PROOF_PERIOD: u64 = 2592000;
+
 /// Minimum validators required
 pub const MIN_VALIDATORS: u32 = 3;
 
@@ -51,3 +102,5 @@ pub const ORACLE_MAX_ORACLES_PER_FEED: u32 = 16;
 pub const ORACLE_DEFAULT_HEARTBEAT: u64 = 60;
 /// Maximum allowed deviation between consecutive values (basis points)
 pub const ORACLE_MAX_DEVIATION_BPS: u32 = 5000;
+/// Maximum age of an oracle price used to value contributions (seconds)
+pub const ORACLE_PRICE_MAX_AGE: u64 = 300;
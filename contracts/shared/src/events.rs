@@ -5,19 +5,42 @@ pub const PROJECT_CREATED: Symbol = symbol_short!("proj_new");
 pub const PROJECT_FUNDED: Symbol = symbol_short!("proj_fund");
 pub const PROJECT_COMPLETED: Symbol = symbol_short!("proj_done");
 pub const PROJECT_FAILED: Symbol = symbol_short!("proj_fail");
+pub const PROJECT_CANCELLED: Symbol = symbol_short!("proj_canc");
+pub const STRETCH_GOAL_REACHED: Symbol = symbol_short!("stretch");
+pub const PRESALE_CONFIGURED: Symbol = symbol_short!("presale");
+pub const RECEIPT_TOKEN_SET: Symbol = symbol_short!("rcpt_tok");
+pub const METADATA_UPDATED: Symbol = symbol_short!("meta_upd");
+pub const AMENDMENT_PROPOSED: Symbol = symbol_short!("amend_new");
+pub const AMENDMENT_APPROVED: Symbol = symbol_short!("amend_ok");
+pub const AMENDMENT_EXECUTED: Symbol = symbol_short!("amend_exe");
+pub const AMENDMENT_CANCELLED: Symbol = symbol_short!("amend_cxl");
+pub const FEE_COLLECTED: Symbol = symbol_short!("fee_coll");
+pub const FEE_CONFIG_UPDATED: Symbol = symbol_short!("fee_cfg");
 
 // Contribution events
 pub const CONTRIBUTION_MADE: Symbol = symbol_short!("contrib");
+pub const CONTRIBUTION_WITHDRAWN: Symbol = symbol_short!("contr_wd");
+pub const TOKEN_CONTRIBUTION_MADE: Symbol = symbol_short!("contr_tok");
+pub const REWARD_TIER_SELECTED: Symbol = symbol_short!("tier_sel");
 pub const REFUND_ISSUED: Symbol = symbol_short!("refund");
+pub const ESCROW_REFUND_CLAIMED: Symbol = symbol_short!("esc_claim");
+pub const REFERRAL_RECORDED: Symbol = symbol_short!("referral");
+pub const REFERRAL_REWARD_CLAIMED: Symbol = symbol_short!("ref_claim");
+pub const MATCHING_POOL_CREATED: Symbol = symbol_short!("match_new");
+pub const MATCHING_POOL_CLOSED: Symbol = symbol_short!("match_end");
+pub const MATCH_APPLIED: Symbol = symbol_short!("matched");
+pub const MATCH_RETURNED: Symbol = symbol_short!("match_ret");
 
 // Escrow events
 pub const ESCROW_INITIALIZED: Symbol = symbol_short!("esc_init");
 pub const FUNDS_LOCKED: Symbol = symbol_short!("lock");
 pub const FUNDS_RELEASED: Symbol = symbol_short!("release");
+pub const ESCROW_REFUNDED: Symbol = symbol_short!("esc_rfnd");
 pub const MILESTONE_CREATED: Symbol = symbol_short!("m_create");
 pub const MILESTONE_SUBMITTED: Symbol = symbol_short!("m_submit");
 pub const MILESTONE_APPROVED: Symbol = symbol_short!("m_apprv");
 pub const MILESTONE_REJECTED: Symbol = symbol_short!("m_reject");
+pub const MILESTONE_RESUBMITTED: Symbol = symbol_short!("m_resub");
 pub const MILESTONE_COMPLETED: Symbol = symbol_short!("milestone");
 pub const VALIDATORS_UPDATED: Symbol = symbol_short!("v_update");
 
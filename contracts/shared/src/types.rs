@@ -16,9 +16,10 @@ pub type Hash = BytesN<32>;
 #[contracttype]
 #[derive(Clone)]
 pub struct FeeConfig {
-    pub platform_fee: BasisPoints, // Platform fee in basis points
-    pub creator_fee: BasisPoints,  // Creator fee in basis points
-    pub fee_recipient: Address,    // Address to receive fees
+    pub platform_fee: BasisPoints,         // Platform fee in basis points
+    pub creator_fee: BasisPoints,          // Creator fee in basis points
+    pub fee_recipient: Address,            // Address to receive fees
+    pub flexible_fee_premium: BasisPoints, // Extra platform fee on flexible projects that finish below goal
 }
 
 /// Token information
@@ -53,6 +54,16 @@ pub enum Jurisdiction {
     UnitedKingdom = 3,
 }
 
+/// Who votes on an escrow's milestones
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MilestoneVoting {
+    /// The escrow's validators vote, one vote each
+    Validators,
+    /// Backers vote, weighted by their contribution recorded in this ProjectLaunch contract
+    Backers(Address),
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct EscrowInfo {
@@ -74,6 +85,33 @@ pub enum MilestoneStatus {
     Rejected = 3,  // Rejected by majority
 }
 
+/// How the part of a milestone not paid on approval is released
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TrancheRelease {
+    /// Vests linearly over this many seconds after approval
+    Linear(u64),
+    /// Released in equal parts as this many follow-up sub-proofs are approved
+    SubProofs(u32),
+}
+
+/// Tranche schedule for a large milestone
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheSchedule {
+    pub upfront_bps: u32, // Share of the milestone released on approval
+    pub remainder: TrancheRelease,
+}
+
+/// Progress of an approved milestone's payout under its tranche schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRelease {
+    pub released: Amount,
+    pub approved_at: Timestamp,
+    pub sub_proofs_approved: u32,
+}
+
 /// Milestone structure
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -284,3 +322,36 @@ pub struct OracleReport {
     pub oracle: Address,
     pub value: Amount,
 }
+
+/// Badge types that can be awarded to users
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum BadgeType {
+    /// Awarded for making contributions to projects
+    Contributor = 0,
+    /// Awarded to verified creators
+    VerifiedCreator = 1,
+    /// Awarded to top investors by volume
+    TopInvestor = 2,
+    /// Awarded for early platform adoption
+    EarlyAdopter = 3,
+    /// Awarded for completing first project
+    FirstProject = 4,
+    /// Awarded for successful milestone completion
+    MilestoneAchiever = 5,
+    /// Awarded for governance participation
+    GovernanceParticipant = 6,
+}
+
+/// User reputation profile
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ReputationProfile {
+    /// User's address
+    pub user: Address,
+    /// Reputation score (clamped between REPUTATION_MIN and REPUTATION_MAX)
+    pub score: i128,
+    /// List of badges earned by the user
+    pub badges: Vec<BadgeType>,
+}
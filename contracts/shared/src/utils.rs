@@ -1,6 +1,7 @@
 use soroban_sdk::Env;
 
-use crate::types::{Amount, BasisPoints};
+use crate::constants::MAX_PLATFORM_FEE;
+use crate::types::{Amount, BasisPoints, FeeConfig};
 
 /// Calculate percentage of an amount
 pub fn calculate_percentage(amount: Amount, basis_points: BasisPoints) -> Amount {
@@ -12,6 +13,15 @@ pub fn calculate_fee(amount: Amount, fee_rate: BasisPoints) -> Amount {
     calculate_percentage(amount, fee_rate)
 }
 
+/// Validate a fee configuration against the platform fee ceiling, premium included
+pub fn validate_fee_config(config: &FeeConfig) -> bool {
+    config
+        .platform_fee
+        .saturating_add(config.flexible_fee_premium)
+        <= MAX_PLATFORM_FEE
+        && validate_basis_points(config.creator_fee)
+}
+
 /// Verify timestamp is in the future
 pub fn verify_future_timestamp(env: &Env, timestamp: u64) -> bool {
     timestamp > env.ledger().timestamp()